@@ -0,0 +1,91 @@
+//! Proleptic Gregorian calendar arithmetic, in `no_std`.
+//!
+//! Days are counted from the UNIX epoch, 1970-01-01.
+
+/// Whether the year is a leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in the month, or 0 if the month is not within `1..=12`.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Days since the UNIX epoch of the date; the date is not validated.
+///
+/// Refer: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+pub fn days_from_civil(year: i32, month: u8, date: u8) -> i64 {
+    let (month, date) = (i64::from(month), i64::from(date));
+    let year = i64::from(year) - i64::from(month <= 2);
+
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + date - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date, as `(year, month, date)`, of the days since the UNIX epoch.
+///
+/// Refer: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + 719_468;
+
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+
+    let date = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year as i32, month, date)
+}
+
+/// Day of the week of the days since the UNIX epoch, counted from Sunday as 0.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn weekday_from_days(days: i64) -> u8 {
+    // The epoch was a Thursday.
+    (days + 4).rem_euclid(7) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{civil_from_days, days_from_civil, days_in_month, weekday_from_days};
+
+    #[test]
+    fn converts_between_dates_and_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 1, 1), 10_957);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+
+        for days in 10_957..47_482 {
+            let (year, month, date) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, date), days);
+        }
+    }
+
+    #[test]
+    fn counts_leap_days_and_weekdays() {
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2100, 2), 28);
+        assert_eq!(days_in_month(2024, 13), 0);
+
+        // 2024-10-07 was a Monday.
+        assert_eq!(weekday_from_days(days_from_civil(2024, 10, 7)), 1);
+    }
+}
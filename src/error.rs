@@ -1,33 +1,73 @@
-/// Error type
-#[derive(Debug)]
-pub struct Error(dyn core::error::Error + Send + Sync);
+use crate::{models::InvalidDateTime, rtc::registers::Register};
+use core::fmt::{self, Debug, Display};
+use embedded_hal::i2c::ErrorKind;
 
-impl core::fmt::Display for Error {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "Error: {}", &self.0)
-    }
+/// Driver error.
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub enum DriverError<E> {
+    /// I2C bus error
+    I2c(E),
+    /// An argument is out of range; names the argument
+    InvalidInput(InvalidInput),
+    /// The date and time read from, or to be written to, the rtc is invalid
+    InvalidDateTime(InvalidDateTime),
+    /// The rtc lost its time through a voltage drop or power-on reset, i.e. V2F is set
+    ClockIntegrityLost,
+    /// A register read back after a read-modify-write does not hold the value written
+    VerifyFailed {
+        /// Register written
+        register: Register,
+        /// Value written
+        written: u8,
+        /// Value read back
+        read: u8,
+    },
+    /// The rtc did not settle within the bounded number of attempts, e.g. a consistent reading
+    /// of the time
+    Timeout,
+    /// The operation is not supported by the rtc
+    Unsupported,
 }
 
-impl core::error::Error for Error {
-    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
-        self.0.source()
-    }
+/// Argument out of range, as reported by [`DriverError::InvalidInput`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum InvalidInput {
+    /// UNIX timestamp outside the years 2000 to 2099
+    Timestamp,
+    /// Frequency offset outside the range of the Offset register
+    Offset,
+    /// Value does not fit in the payload nibble of a checked RAM value
+    RamValue,
+    /// Century outside 1900 to 3400, so it cannot be kept in user RAM
+    Century,
+}
 
-    fn description(&self) -> &str {
-        "description() is deprecated; use Display"
+impl InvalidInput {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Timestamp => "UNIX timestamp outside the years 2000 to 2099",
+            Self::Offset => "frequency offset outside the range of the Offset register",
+            Self::RamValue => "value does not fit in a checked RAM value",
+            Self::Century => "century cannot be kept in user RAM",
+        }
     }
+}
 
-    fn cause(&self) -> Option<&dyn core::error::Error> {
-        self.source()
+impl Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
-/// Driver error.
-#[derive(Debug)]
-#[allow(clippy::module_name_repetitions)]
-pub enum DriverError<E> {
-    /// I2C bus error
-    I2c(E),
+impl core::error::Error for InvalidInput {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for InvalidInput {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{=str}", self.as_str());
+    }
 }
 
 impl<E> From<E> for DriverError<E> {
@@ -35,3 +75,103 @@ impl<E> From<E> for DriverError<E> {
         self::DriverError::I2c(other)
     }
 }
+
+impl<E: Debug> Display for DriverError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I2c(e) => write!(f, "I2C bus error: {e:?}"),
+            Self::InvalidInput(input) => write!(f, "invalid input: {input}"),
+            Self::InvalidDateTime(field) => write!(f, "invalid date and time: {field}"),
+            Self::ClockIntegrityLost => f.write_str("clock integrity lost, V2F is set"),
+            Self::VerifyFailed {
+                register,
+                written,
+                read,
+            } => write!(
+                f,
+                "register {:#04x} read back as {read:#010b}, but {written:#010b} was written",
+                register.address()
+            ),
+            Self::Timeout => f.write_str("timed out"),
+            Self::Unsupported => f.write_str("operation not supported"),
+        }
+    }
+}
+
+impl<E: Debug> core::error::Error for DriverError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidInput(input) => Some(input),
+            Self::InvalidDateTime(field) => Some(field),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<E: Debug> defmt::Format for DriverError<E> {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            Self::I2c(e) => defmt::write!(fmt, "I2C bus error: {}", defmt::Debug2Format(e)),
+            Self::InvalidInput(input) => defmt::write!(fmt, "invalid input: {}", input),
+            Self::InvalidDateTime(field) => {
+                defmt::write!(fmt, "invalid date and time: {}", field);
+            }
+            Self::ClockIntegrityLost => defmt::write!(fmt, "clock integrity lost, V2F is set"),
+            Self::VerifyFailed {
+                register,
+                written,
+                read,
+            } => defmt::write!(
+                fmt,
+                "register {=u8:#x} read back as {=u8:#b}, but {=u8:#b} was written",
+                register.address(),
+                read,
+                written,
+            ),
+            Self::Timeout => defmt::write!(fmt, "timed out"),
+            Self::Unsupported => defmt::write!(fmt, "operation not supported"),
+        }
+    }
+}
+
+impl<E: embedded_hal::i2c::Error> embedded_hal::i2c::Error for DriverError<E> {
+    /// The kind of the bus error; any other error is [`ErrorKind::Other`].
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::I2c(e) => e.kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DriverError, InvalidInput};
+    use crate::rtc::registers::Register;
+    use embedded_hal::i2c::{Error, ErrorKind, NoAcknowledgeSource};
+
+    #[test]
+    fn maps_to_error_kind() {
+        let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+        assert_eq!(DriverError::I2c(nack).kind(), nack);
+        assert_eq!(
+            DriverError::<ErrorKind>::InvalidInput(InvalidInput::Offset).kind(),
+            ErrorKind::Other
+        );
+    }
+
+    #[test]
+    fn displays_what_went_wrong() {
+        let error = DriverError::<ErrorKind>::VerifyFailed {
+            register: Register::Control,
+            written: 0b0000_1000,
+            read: 0,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "register 0x1f read back as 0b00000000, but 0b00001000 was written"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Integrations with third-party date and time, serialisation and formatting crates.
+
+/// Splits a year into the century and the year within it, as held by [`crate::ClockData`].
+///
+/// Years outside the range of a `u16` yield a century of 0, which
+/// [`crate::ClockData::validate`] rejects.
+#[cfg(any(feature = "chrono", feature = "time"))]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn split_year(year: i32) -> (u16, u8) {
+    let within = year.rem_euclid(100);
+
+    (u16::try_from(year - within).unwrap_or(0), within as u8)
+}
+
+/// Nanoseconds per hundredth of a second, the resolution of the rtc.
+#[cfg(any(feature = "chrono", feature = "time"))]
+const NANOS_PER_HUNDREDTH: u32 = 10_000_000;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+
+#[cfg(feature = "serde")]
+pub use self::serde::iso8601;
@@ -0,0 +1,87 @@
+//! Conversions between [`ClockData`] and [`chrono`] types.
+
+use super::{split_year, NANOS_PER_HUNDREDTH};
+use crate::models::{ClockData, InvalidDateTime};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+impl TryFrom<ClockData> for NaiveDateTime {
+    type Error = InvalidDateTime;
+
+    /// Converts the reading, including its century.
+    fn try_from(data: ClockData) -> Result<Self, Self::Error> {
+        data.validate_date_time()?;
+
+        NaiveDate::from_ymd_opt(
+            i32::from(data.full_year()),
+            u32::from(data.month()),
+            u32::from(data.date()),
+        )
+        .and_then(|date| {
+            date.and_hms_nano_opt(
+                u32::from(data.hours()),
+                u32::from(data.minutes()),
+                u32::from(data.seconds()),
+                u32::from(data.hundredths()) * NANOS_PER_HUNDREDTH,
+            )
+        })
+        .ok_or(InvalidDateTime::Date)
+    }
+}
+
+impl From<NaiveDateTime> for ClockData {
+    /// Converts the date and time, truncated to hundredths of a second. The weekday register bit
+    /// is derived from the date.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from(value: NaiveDateTime) -> Self {
+        // A leap second is represented as 59 seconds, with over 1s worth of nanoseconds.
+        let hundredths = (value.nanosecond() / NANOS_PER_HUNDREDTH).min(99);
+
+        let (century, year) = split_year(value.year());
+
+        ClockData {
+            hundredths: hundredths as u8,
+            seconds: value.second() as u8,
+            minutes: value.minute() as u8,
+            hours: value.hour() as u8,
+            weekday: 1 << value.weekday().num_days_from_sunday(),
+            date: value.day() as u8,
+            month: value.month() as u8,
+            year,
+            century,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ClockData, InvalidDateTime, Weekday};
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    #[test]
+    fn round_trips_naive_date_time() {
+        let value = NaiveDate::from_ymd_opt(2024, 10, 7)
+            .unwrap()
+            .and_hms_milli_opt(12, 34, 56, 780)
+            .unwrap();
+
+        let data = ClockData::from(value);
+        assert_eq!(data.year(), 24);
+        assert_eq!(data.hundredths(), 78);
+        assert_eq!(data.weekday(), Weekday::Monday as u8);
+
+        assert_eq!(NaiveDateTime::try_from(data), Ok(value));
+    }
+
+    #[test]
+    fn rejects_invalid_date() {
+        let mut data = ClockData::from(
+            NaiveDate::from_ymd_opt(2023, 2, 28)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        );
+        data.date = 29;
+
+        assert_eq!(NaiveDateTime::try_from(data), Err(InvalidDateTime::Date));
+    }
+}
@@ -0,0 +1,117 @@
+//! ISO 8601 representation of [`ClockData`](crate::ClockData) for `serde`.
+
+/// Serialises a [`ClockData`](crate::ClockData) as an ISO 8601 string, e.g.
+/// `2024-10-07T12:34:56.78`, rather than field by field; for use with
+/// `#[serde(with = "rv8803::iso8601")]`.
+///
+/// Deserialisation accepts either layout of [`ClockData::from_str`](crate::ClockData), and
+/// rejects an invalid date and time.
+pub mod iso8601 {
+    use crate::models::{ClockData, Format};
+    use core::fmt;
+    use serde::{de, Deserializer, Serializer};
+
+    /// Longest ISO 8601 string, with a five-digit year.
+    const MAX_LEN: usize = 23;
+
+    /// Serialises the date and time as an ISO 8601 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error, or a custom error should the year not fit in 5 digits.
+    pub fn serialize<S: Serializer>(data: &ClockData, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buf = [0u8; MAX_LEN];
+        let formatted = data
+            .format_into(&mut buf, Format::Iso8601)
+            .map_err(serde::ser::Error::custom)?;
+
+        serializer.serialize_str(formatted)
+    }
+
+    /// Deserialises the date and time from an ISO 8601 or compact string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer's error, or a custom error should the string not parse.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ClockData, D::Error> {
+        deserializer.deserialize_str(Iso8601Visitor)
+    }
+
+    struct Iso8601Visitor;
+
+    impl de::Visitor<'_> for Iso8601Visitor {
+        type Value = ClockData;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 8601 date and time")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+            s.parse().map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        models::{CurrentYear, DateTimeBuilder, Month},
+        rtc::{alarm::Alarm, timer::CountdownTimer},
+        ClockData,
+    };
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    struct Reading {
+        #[serde(with = "super::iso8601")]
+        at: ClockData,
+    }
+
+    #[test]
+    fn serialises_field_wise_or_as_iso8601() {
+        let data = DateTimeBuilder::new()
+            .year(CurrentYear::new(2024))
+            .month(Month::October)
+            .date(7)
+            .hours(12)
+            .try_build()
+            .unwrap();
+
+        let json = serde_json::to_string(&Reading { at: data }).unwrap();
+        assert_eq!(json, r#"{"at":"2024-10-07T12:00:00.00"}"#);
+        let reading: Reading = serde_json::from_str(&json).unwrap();
+        assert_eq!(reading.at.to_string(), data.to_string());
+        assert!(serde_json::from_str::<Reading>(r#"{"at":"2024-02-30T00:00:00"}"#).is_err());
+
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"hundredths":0,"seconds":0,"minutes":0,"hours":12,"weekday":2,"#,
+                r#""date":7,"month":10,"year":24,"century":2000}"#
+            )
+        );
+        let round_trip: ClockData = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip.to_string(), data.to_string());
+    }
+
+    #[test]
+    fn rejects_an_invalid_field_wise_date_and_time() {
+        let json = concat!(
+            r#"{"hundredths":0,"seconds":0,"minutes":0,"hours":25,"weekday":2,"#,
+            r#""date":7,"month":10,"year":24,"century":2000}"#
+        );
+        let error = serde_json::from_str::<ClockData>(json).unwrap_err();
+        assert!(error.to_string().starts_with("hours out of range"));
+    }
+
+    #[test]
+    fn rejects_out_of_range_configuration() {
+        assert!(serde_json::from_str::<CountdownTimer>(r#"{"clock":"Hz1","ticks":10}"#).is_ok());
+        assert!(serde_json::from_str::<CountdownTimer>(r#"{"clock":"Hz1","ticks":0}"#).is_err());
+
+        let alarm = r#"{"minute":30,"hour":7,"day":{"Date":15}}"#;
+        assert!(serde_json::from_str::<Alarm>(alarm).is_ok());
+        let alarm = r#"{"minute":75,"hour":null,"day":null}"#;
+        assert!(serde_json::from_str::<Alarm>(alarm).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! Conversions between [`ClockData`] and [`time`] types.
+
+use super::{split_year, NANOS_PER_HUNDREDTH};
+use crate::models::{ClockData, InvalidDateTime};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+impl TryFrom<ClockData> for PrimitiveDateTime {
+    type Error = InvalidDateTime;
+
+    /// Converts the reading, including its century.
+    fn try_from(data: ClockData) -> Result<Self, Self::Error> {
+        data.validate_date_time()?;
+
+        let month = Month::try_from(data.month()).map_err(|_| InvalidDateTime::Month)?;
+        let date = Date::from_calendar_date(i32::from(data.full_year()), month, data.date())
+            .map_err(|_| InvalidDateTime::Date)?;
+        let time = Time::from_hms_nano(
+            data.hours(),
+            data.minutes(),
+            data.seconds(),
+            u32::from(data.hundredths()) * NANOS_PER_HUNDREDTH,
+        )
+        .map_err(|_| InvalidDateTime::Hours)?;
+
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+}
+
+impl TryFrom<ClockData> for OffsetDateTime {
+    type Error = InvalidDateTime;
+
+    /// Converts the reading, taking the rtc to be set to UTC.
+    fn try_from(data: ClockData) -> Result<Self, Self::Error> {
+        Ok(PrimitiveDateTime::try_from(data)?.assume_utc())
+    }
+}
+
+impl From<PrimitiveDateTime> for ClockData {
+    /// Converts the date and time, truncated to hundredths of a second. The weekday register bit
+    /// is derived from the date.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from(value: PrimitiveDateTime) -> Self {
+        let (century, year) = split_year(value.year());
+
+        ClockData {
+            hundredths: (value.nanosecond() / NANOS_PER_HUNDREDTH) as u8,
+            seconds: value.second(),
+            minutes: value.minute(),
+            hours: value.hour(),
+            weekday: 1 << value.weekday().number_days_from_sunday(),
+            date: value.day(),
+            month: value.month() as u8,
+            year,
+            century,
+        }
+    }
+}
+
+impl TryFrom<OffsetDateTime> for ClockData {
+    type Error = InvalidDateTime;
+
+    /// Converts the date and time to UTC, then as per [`From<PrimitiveDateTime>`].
+    ///
+    /// Fails only if the conversion to UTC falls outside the range of [`OffsetDateTime`].
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        let utc = value
+            .checked_to_offset(UtcOffset::UTC)
+            .ok_or(InvalidDateTime::Year)?;
+
+        Ok(ClockData::from(PrimitiveDateTime::new(
+            utc.date(),
+            utc.time(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{ClockData, InvalidDateTime, Weekday};
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    #[test]
+    fn round_trips_primitive_date_time() {
+        let value = PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::October, 7).unwrap(),
+            Time::from_hms_milli(12, 34, 56, 780).unwrap(),
+        );
+
+        let data = ClockData::from(value);
+        assert_eq!(data.year(), 24);
+        assert_eq!(data.hundredths(), 78);
+        assert_eq!(data.weekday(), Weekday::Monday as u8);
+
+        assert_eq!(PrimitiveDateTime::try_from(data), Ok(value));
+        assert_eq!(OffsetDateTime::try_from(data), Ok(value.assume_utc()));
+    }
+
+    #[test]
+    fn converts_offset_date_time_to_utc() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let value = PrimitiveDateTime::new(
+            Date::from_calendar_date(2025, Month::January, 1).unwrap(),
+            Time::from_hms(1, 0, 0).unwrap(),
+        )
+        .assume_offset(offset);
+
+        let data = ClockData::try_from(value).unwrap();
+        assert_eq!((data.year(), data.month(), data.date()), (24, 12, 31));
+        assert_eq!(data.hours(), 23);
+        assert_eq!(data.weekday(), Weekday::Tuesday as u8);
+    }
+
+    #[test]
+    fn rejects_invalid_date() {
+        let mut data = ClockData::new();
+        data.month = 13;
+        data.date = 1;
+
+        assert_eq!(
+            PrimitiveDateTime::try_from(data),
+            Err(InvalidDateTime::Month)
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! `ufmt` formatting of the public types, matching their `core::fmt::Display`.
+
+use crate::error::{DriverError, InvalidInput};
+use crate::log::LoggableClockData;
+use crate::models::{ClockData, InvalidDateTime, Month, Weekday};
+use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+use ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+impl uDisplay for ClockData {
+    /// Formats as ISO 8601, e.g. `2024-10-07T00:00:00.00`.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        write_padded(f, self.full_year(), 4)?;
+        for (separator, value) in [
+            ('-', self.month),
+            ('-', self.date),
+            ('T', self.hours),
+            (':', self.minutes),
+            (':', self.seconds),
+            ('.', self.hundredths),
+        ] {
+            f.write_char(separator)?;
+            write_padded(f, u16::from(value), 2)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl uDisplay for LoggableClockData {
+    /// Formats as ISO 8601, e.g. `2024-10-07T00:00:00.00`.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(&self.data(), f)
+    }
+}
+
+impl uDisplay for Weekday {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.name())
+    }
+}
+
+impl uDisplay for Month {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.name())
+    }
+}
+
+impl uDisplay for InvalidDateTime {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+impl uDisplay for InvalidInput {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<E: embedded_hal::i2c::Error> uDisplay for DriverError<E> {
+    /// As per `Display`, the bus error being described by its [`ErrorKind`], and register
+    /// values in hexadecimal.
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::I2c(e) => uwrite!(f, "I2C bus error: {}", kind_str(e.kind())),
+            Self::InvalidInput(input) => uwrite!(f, "invalid input: {}", input),
+            Self::InvalidDateTime(field) => uwrite!(f, "invalid date and time: {}", field),
+            Self::ClockIntegrityLost => f.write_str("clock integrity lost, V2F is set"),
+            Self::VerifyFailed {
+                register,
+                written,
+                read,
+            } => uwrite!(
+                f,
+                "register {:#04x} read back as {:#04x}, but {:#04x} was written",
+                register.address(),
+                *read,
+                *written
+            ),
+            Self::Timeout => f.write_str("timed out"),
+            Self::Unsupported => f.write_str("operation not supported"),
+        }
+    }
+}
+
+impl<E: embedded_hal::i2c::Error> uDebug for DriverError<E> {
+    /// As per `Debug`, the bus error being described by its [`ErrorKind`].
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self {
+            Self::I2c(e) => uwrite!(f, "I2c({})", kind_str(e.kind())),
+            Self::InvalidInput(input) => f.debug_tuple("InvalidInput")?.field(input)?.finish(),
+            Self::InvalidDateTime(field) => {
+                f.debug_tuple("InvalidDateTime")?.field(field)?.finish()
+            }
+            Self::ClockIntegrityLost => f.write_str("ClockIntegrityLost"),
+            Self::VerifyFailed {
+                register,
+                written,
+                read,
+            } => f
+                .debug_struct("VerifyFailed")?
+                .field("register", register)?
+                .field("written", written)?
+                .field("read", read)?
+                .finish(),
+            Self::Timeout => f.write_str("Timeout"),
+            Self::Unsupported => f.write_str("Unsupported"),
+        }
+    }
+}
+
+/// Describes a bus error, as its `Display` does.
+fn kind_str(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Bus => "Bus error occurred",
+        ErrorKind::ArbitrationLoss => "The arbitration was lost",
+        ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address) => {
+            "The device did not acknowledge its address"
+        }
+        ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data) => {
+            "The device did not acknowledge the data"
+        }
+        ErrorKind::NoAcknowledge(_) => "The device did not acknowledge its address or the data",
+        ErrorKind::Overrun => "The peripheral receive buffer was overrun",
+        _ => "A different error occurred",
+    }
+}
+
+/// Writes `value` in decimal, zero-padded to `width` digits; `ufmt` has no padding of its own.
+fn write_padded<W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    mut value: u16,
+    width: usize,
+) -> Result<(), W::Error> {
+    let mut digits = [b'0'; 5];
+    let mut start = digits.len();
+    while value > 0 {
+        start -= 1;
+        digits[start] += (value % 10) as u8;
+        value /= 10;
+    }
+    let start = start.min(digits.len() - width);
+
+    for &digit in &digits[start..] {
+        f.write_char(char::from(digit))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{DriverError, InvalidInput};
+    use crate::log::LoggableClockData;
+    use crate::models::{ClockData, InvalidDateTime, Month, Weekday};
+    use crate::rtc::registers::Register;
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+    use ufmt::uwrite;
+
+    #[test]
+    fn formats_like_display() {
+        let data: ClockData = "2024-10-07T09:08:07.06".parse().unwrap();
+        let mut s = String::new();
+
+        uwrite!(
+            s,
+            "{} {} {} {}",
+            data,
+            LoggableClockData::new(data),
+            Weekday::Monday,
+            Month::October
+        )
+        .unwrap();
+        assert_eq!(
+            s,
+            "2024-10-07T09:08:07.06 2024-10-07T09:08:07.06 Monday October"
+        );
+
+        let mut s = String::new();
+        let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+        uwrite!(s, "{}", DriverError::I2c(nack)).unwrap();
+        assert_eq!(
+            s,
+            "I2C bus error: The device did not acknowledge its address"
+        );
+
+        for error in [
+            DriverError::<ErrorKind>::InvalidInput(InvalidInput::Offset),
+            DriverError::InvalidDateTime(InvalidDateTime::Hours),
+            DriverError::Timeout,
+            DriverError::Unsupported,
+        ] {
+            let mut s = String::new();
+            uwrite!(s, "{}", error).unwrap();
+            assert_eq!(s, error.to_string());
+        }
+
+        let mut s = String::new();
+        let error = DriverError::<ErrorKind>::VerifyFailed {
+            register: Register::Control,
+            written: 0x08,
+            read: 0,
+        };
+        uwrite!(s, "{}; {:?}", error, error).unwrap();
+        assert_eq!(
+            s,
+            "register 0x1f read back as 0x00, but 0x08 was written; \
+             VerifyFailed { register: Control, written: 8, read: 0 }"
+        );
+    }
+}
@@ -16,6 +16,11 @@ impl<'a> ByteMutWriter<'a> {
         str::from_utf8(&self.buf[0..self.cursor]).expect("Unable to create &str from buf")
     }
 
+    /// Consumes the writer, returning what was written.
+    pub fn into_str(self) -> &'a str {
+        str::from_utf8(&self.buf[0..self.cursor]).expect("Unable to create &str from buf")
+    }
+
     #[inline]
     pub fn capacity(&self) -> usize {
         self.buf.len()
@@ -39,16 +44,16 @@ impl<'a> ByteMutWriter<'a> {
 }
 
 impl fmt::Write for ByteMutWriter<'_> {
+    /// Appends `s`, or fails without writing anything should it not fit.
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        let cap = self.capacity();
-        self.buf[self.cursor..cap]
-            .iter_mut()
-            .zip(s.as_bytes().iter())
-            .for_each(|(i, &b)| {
-                *i = b;
-            });
-
-        self.cursor = usize::min(cap, self.cursor + s.as_bytes().len());
+        let end = self.cursor + s.len();
+        if end > self.capacity() {
+            return Err(fmt::Error);
+        }
+
+        self.buf[self.cursor..end].copy_from_slice(s.as_bytes());
+        self.cursor = end;
+
         Ok(())
     }
 }
@@ -1,5 +1,5 @@
 //! RTC clock driver for the `rv8803` chip over I2C.
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 #![forbid(unsafe_code)]
 #![deny(missing_docs, dead_code, clippy::unwrap_used)]
 #![cfg_attr(docsrs, feature(doc_cfg), feature(doc_auto_cfg))]
@@ -9,18 +9,38 @@ pub use crate::models::ClockData;
 pub use crate::rtc::Driver;
 pub use crate::rtc::DriverAsync;
 
+#[cfg(feature = "serde")]
+pub use crate::ext::iso8601;
+
+pub(crate) mod calendar;
 pub(crate) mod error;
+pub(crate) mod ext;
 #[allow(dead_code)]
 pub(crate) mod formatter;
 pub(crate) mod log;
 pub(crate) mod models;
+pub(crate) mod parse;
 pub(crate) mod rtc;
+pub(crate) mod trace;
 
 /// Re-exports
 pub mod prelude {
-    pub use crate::error::DriverError;
+    pub use crate::error::{DriverError, InvalidInput};
     pub use crate::log::LoggableClockData;
-    pub use crate::models::{CurrentYear, DateTimeBuilder, Month, Weekday, Year};
+    pub use crate::models::{
+        CurrentYear, DateTimeBuilder, Format, InvalidDateTime, Month, Weekday, DEFAULT_CENTURY,
+        MAX_UNIX_TIMESTAMP, MIN_UNIX_TIMESTAMP,
+    };
+    pub use crate::parse::ParseError;
     pub use crate::rtc::address::SlaveAddress;
+    pub use crate::rtc::alarm::{Alarm, AlarmDay};
+    pub use crate::rtc::bitfields::{Bitfield, ControlReg, EventControlReg, ExtensionReg, FlagReg};
+    pub use crate::rtc::clkout::ClockOutFrequency;
+    pub use crate::rtc::event::{EventEdge, EventFilter, EventInput};
+    pub use crate::rtc::offset::{MAX_OFFSET_PPM, MIN_OFFSET_PPM, OFFSET_STEP_PPM};
+    pub use crate::rtc::periodic::UpdatePeriod;
+    pub use crate::rtc::ram::RamValue;
+    pub use crate::rtc::registers::Register;
+    pub use crate::rtc::timer::{CountdownTimer, TimerClock};
     pub use crate::rtc::AddressingMode;
 }
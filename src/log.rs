@@ -1,34 +1,28 @@
-use crate::{models::Year, ClockData};
+use crate::ClockData;
+use core::fmt::{self, Display};
 
 /// Loggable newtype for [`ClockData`]
 #[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub struct LoggableClockData {
     data: ClockData,
-    /// Current century
-    century: Year,
 }
 
 impl LoggableClockData {
     /// Creates a [`LoggableClockData`]
     pub fn new(data: ClockData) -> Self {
-        Self {
-            data,
-            century: Year::default(),
-        }
-    }
-
-    /// Set the century. This is mainly used for presentational purposes.
-    pub fn set_century(&mut self, value: Year) {
-        self.century = value
+        Self { data }
     }
 
     /// Get the clock data.
     pub fn data(&self) -> ClockData {
         self.data
     }
+}
 
-    /// Get the century.
-    pub fn century(&self) -> Year {
-        self.century
+impl Display for LoggableClockData {
+    /// Formats as ISO 8601, e.g. `2024-10-07T00:00:00.00`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.data.fmt(f)
     }
 }
@@ -1,8 +1,36 @@
-use crate::{formatter::ByteMutWriter, log::LoggableClockData};
-use core::fmt::{Debug, Write};
+use crate::formatter::ByteMutWriter;
+#[cfg(feature = "defmt")]
+use crate::log::LoggableClockData;
+use core::fmt::{self, Debug, Display, Write};
+
+/// Earliest supported UNIX timestamp, 2000-01-01T00:00:00Z.
+pub const MIN_UNIX_TIMESTAMP: u64 = 946_684_800;
+
+/// Latest supported UNIX timestamp, 2099-12-31T23:59:59Z.
+pub const MAX_UNIX_TIMESTAMP: u64 = 4_102_444_799;
+
+/// Century of the two-digit year for UNIX timestamps.
+const UNIX_CENTURY: u16 = 2000;
+
+/// Century of the two-digit year, unless set otherwise.
+pub const DEFAULT_CENTURY: u16 = 2000;
+
+/// Earliest full year accepted by [`ClockData::validate`].
+const MIN_YEAR: u16 = 1970;
+
+/// Latest full year accepted by [`ClockData::validate`].
+const MAX_YEAR: u16 = 2199;
 
 /// Holds the clock data.
-#[derive(Debug, Copy, Clone, Default)]
+///
+/// With the `serde` feature, it is serialised field by field, and deserialised rejecting an
+/// invalid date and time, as per [`ClockData::validate`]; see `rv8803::iso8601` for an ISO 8601
+/// string instead.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ClockDataFields"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub struct ClockData {
     /// Hundredths.
     pub hundredths: u8,
@@ -18,8 +46,63 @@ pub struct ClockData {
     pub date: u8,
     /// Month.
     pub month: u8,
-    /// Year.
+    /// Year, within the century.
     pub year: u8,
+    /// Century, e.g. `2000`; not stored by the rtc, but tracked by the [`crate::Driver`].
+    pub century: u16,
+}
+
+/// Fields of a deserialised [`ClockData`], checked by [`ClockData::validate`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ClockDataFields {
+    hundredths: u8,
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    weekday: u8,
+    date: u8,
+    month: u8,
+    year: u8,
+    century: u16,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ClockDataFields> for ClockData {
+    type Error = InvalidDateTime;
+
+    fn try_from(fields: ClockDataFields) -> Result<Self, Self::Error> {
+        let data = Self {
+            hundredths: fields.hundredths,
+            seconds: fields.seconds,
+            minutes: fields.minutes,
+            hours: fields.hours,
+            weekday: fields.weekday,
+            date: fields.date,
+            month: fields.month,
+            year: fields.year,
+            century: fields.century,
+        };
+        data.validate()?;
+
+        Ok(data)
+    }
+}
+
+impl Default for ClockData {
+    fn default() -> Self {
+        Self {
+            hundredths: 0,
+            seconds: 0,
+            minutes: 0,
+            hours: 0,
+            weekday: 0,
+            date: 0,
+            month: 0,
+            year: 0,
+            century: DEFAULT_CENTURY,
+        }
+    }
 }
 
 impl ClockData {
@@ -73,17 +156,291 @@ impl ClockData {
         self.month
     }
 
-    /// Year.
+    /// Year, within the century.
     #[must_use]
     pub fn year(&self) -> u8 {
         self.year
     }
 
+    /// Century, e.g. `2000`.
+    #[must_use]
+    pub fn century(&self) -> u16 {
+        self.century
+    }
+
+    /// Full year, i.e. the century plus the year within it.
+    #[must_use]
+    pub fn full_year(&self) -> u16 {
+        self.century.saturating_add(u16::from(self.year))
+    }
+
+    /// Decodes the time registers, Hundredths (0x10) through Year (0x17).
+    pub(crate) fn from_registers(regs: &[u8; crate::rtc::now::TIME_REGISTERS_LEN]) -> Self {
+        use misc::bcd_to_dec;
+
+        ClockData {
+            hundredths: bcd_to_dec(regs[0]),
+            seconds: bcd_to_dec(regs[1]),
+            minutes: bcd_to_dec(regs[2]),
+            hours: bcd_to_dec(regs[3]),
+            // Read directly as a byte.
+            weekday: regs[4],
+            date: bcd_to_dec(regs[5]),
+            month: bcd_to_dec(regs[6]),
+            year: bcd_to_dec(regs[7]),
+            century: DEFAULT_CENTURY,
+        }
+    }
+
+    /// Encodes the time registers, Seconds (0x11) through Year (0x17).
+    pub(crate) fn to_registers(self) -> [u8; crate::rtc::now::TIME_REGISTERS_LEN - 1] {
+        use misc::dec_to_bcd;
+
+        [
+            dec_to_bcd(self.seconds),
+            dec_to_bcd(self.minutes),
+            dec_to_bcd(self.hours),
+            // Single bit value only
+            self.weekday,
+            dec_to_bcd(self.date),
+            dec_to_bcd(self.month),
+            dec_to_bcd(self.year),
+        ]
+    }
+
+    /// Checks that every field is within range, including the length of the month, and that
+    /// the weekday is a single register bit. The full year must be within 1970 to 2199.
+    ///
+    /// The rtc treats every year divisible by 4 as a leap year, so counts a 29 February 2100
+    /// that is rejected here.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first field found out of range, as an [`InvalidDateTime`].
+    pub fn validate(&self) -> Result<(), InvalidDateTime> {
+        if self.weekday.count_ones() != 1 || self.weekday > Weekday::Saturday as u8 {
+            return Err(InvalidDateTime::Weekday);
+        }
+
+        self.validate_date_time()
+    }
+
+    /// Weekday, checked against the weekday derived from the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDateTime::Weekday`] if the weekday register disagrees with the date,
+    /// or the first other field found out of range.
+    pub fn weekday_checked(&self) -> Result<Weekday, InvalidDateTime> {
+        self.validate_date_time()?;
+
+        let expected = Weekday::from_date(i32::from(self.full_year()), self.month, self.date);
+        if self.weekday != expected as u8 {
+            return Err(InvalidDateTime::Weekday);
+        }
+
+        Ok(expected)
+    }
+
+    /// Checks every field as per [`ClockData::validate`], other than the weekday.
+    pub(crate) fn validate_date_time(&self) -> Result<(), InvalidDateTime> {
+        use crate::calendar::days_in_month;
+
+        if self.year > 99
+            || self.century % 100 != 0
+            || !(MIN_YEAR..=MAX_YEAR).contains(&self.full_year())
+        {
+            return Err(InvalidDateTime::Year);
+        }
+        if !(1..=12).contains(&self.month) {
+            return Err(InvalidDateTime::Month);
+        }
+        if !(1..=days_in_month(i32::from(self.full_year()), self.month)).contains(&self.date) {
+            return Err(InvalidDateTime::Date);
+        }
+        if self.hours > 23 {
+            return Err(InvalidDateTime::Hours);
+        }
+        if self.minutes > 59 {
+            return Err(InvalidDateTime::Minutes);
+        }
+        if self.seconds > 59 {
+            return Err(InvalidDateTime::Seconds);
+        }
+        if self.hundredths > 99 {
+            return Err(InvalidDateTime::Hundredths);
+        }
+
+        Ok(())
+    }
+
+    /// Seconds since the UNIX epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidDateTime`] if the clock data does not hold a valid date and time.
+    pub fn to_unix_timestamp(&self) -> Result<u64, InvalidDateTime> {
+        use crate::calendar::days_from_civil;
+
+        self.validate_date_time()?;
+
+        let days = days_from_civil(i32::from(self.full_year()), self.month, self.date);
+        let seconds = days * 86_400
+            + i64::from(self.hours) * 3600
+            + i64::from(self.minutes) * 60
+            + i64::from(self.seconds);
+
+        // Validated years start from 1970, so are never before the epoch.
+        u64::try_from(seconds).map_err(|_| InvalidDateTime::Year)
+    }
+
+    /// Milliseconds since the UNIX epoch, with a resolution of hundredths of a second.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidDateTime`] if the clock data does not hold a valid date and time.
+    pub fn to_unix_timestamp_millis(&self) -> Result<u64, InvalidDateTime> {
+        let seconds = self.to_unix_timestamp()?;
+
+        Ok(seconds * 1000 + u64::from(self.hundredths) * 10)
+    }
+
+    /// Creates a [`ClockData`] from seconds since the UNIX epoch, deriving the weekday. The
+    /// century is 2000.
+    ///
+    /// Returns `None` unless the timestamp is within [`MIN_UNIX_TIMESTAMP`] to
+    /// [`MAX_UNIX_TIMESTAMP`], i.e. the years 2000 to 2099.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_unix_timestamp(seconds: u64) -> Option<Self> {
+        use crate::calendar::{civil_from_days, weekday_from_days};
+
+        if !(MIN_UNIX_TIMESTAMP..=MAX_UNIX_TIMESTAMP).contains(&seconds) {
+            return None;
+        }
+
+        // Within range, so neither the days nor the time of day can overflow.
+        let days = (seconds / 86_400) as i64;
+        let time_of_day = seconds % 86_400;
+        let (year, month, date) = civil_from_days(days);
+
+        Some(ClockData {
+            hundredths: 0,
+            seconds: (time_of_day % 60) as u8,
+            minutes: (time_of_day / 60 % 60) as u8,
+            hours: (time_of_day / 3600) as u8,
+            weekday: 1 << weekday_from_days(days),
+            date,
+            month,
+            year: (year - i32::from(UNIX_CENTURY)) as u8,
+            century: UNIX_CENTURY,
+        })
+    }
+
+    /// Creates a [`ClockData`] from milliseconds since the UNIX epoch, truncated to hundredths
+    /// of a second.
+    ///
+    /// Returns `None` unless the timestamp is within the years 2000 to 2099.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_unix_timestamp_millis(millis: u64) -> Option<Self> {
+        let mut data = Self::from_unix_timestamp(millis / 1000)?;
+        data.hundredths = (millis % 1000 / 10) as u8;
+
+        Some(data)
+    }
+
+    /// Formats the date and time into `buf`, returning the part of `buf` written.
+    ///
+    /// A valid date and time takes at most 25 bytes, as [`Format::Rfc2822`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`fmt::Error`] should `buf` be too small, or, for [`Format::Rfc2822`], should
+    /// the month be invalid.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        format: Format,
+    ) -> Result<&'a str, fmt::Error> {
+        let mut writer = ByteMutWriter::new(buf);
+        self.write_format(&mut writer, format)?;
+
+        Ok(writer.into_str())
+    }
+
+    fn write_format<W: Write>(&self, w: &mut W, format: Format) -> fmt::Result {
+        use crate::calendar::{days_from_civil, weekday_from_days};
+
+        let (hours, minutes, seconds) = (self.hours, self.minutes, self.seconds);
+        let (year, month, date) = (self.full_year(), self.month, self.date);
+
+        match format {
+            Format::Iso8601 => write!(
+                w,
+                "{year:04}-{month:02}-{date:02}T{hours:02}:{minutes:02}:{seconds:02}.{:02}",
+                self.hundredths
+            ),
+            Format::Rfc2822 => {
+                let month_name = MONTH_ABBREVIATIONS
+                    .get(usize::from(month).wrapping_sub(1))
+                    .ok_or(fmt::Error)?;
+                let weekday = weekday_from_days(days_from_civil(i32::from(year), month, date));
+                let weekday_name = WEEKDAY_ABBREVIATIONS[usize::from(weekday)];
+
+                write!(
+                    w,
+                    "{weekday_name}, {date:02} {month_name} {year:04} \
+                     {hours:02}:{minutes:02}:{seconds:02}"
+                )
+            }
+            Format::Compact => write!(
+                w,
+                "{:02}{month:02}{date:02}{hours:02}{minutes:02}{seconds:02}",
+                self.year
+            ),
+        }
+    }
+
     /// Set the date and time.  Hundredths is set to 0.
     pub fn set(&mut self, value: &ClockData) {
         *self = *value;
     }
 
+    /// Moves the time back by one minute, carrying into the hour, date and weekday.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub(crate) fn previous_minute(&mut self) {
+        use crate::calendar::{civil_from_days, days_from_civil};
+
+        if self.minutes > 0 {
+            self.minutes -= 1;
+            return;
+        }
+        self.minutes = 59;
+
+        if self.hours > 0 {
+            self.hours -= 1;
+            return;
+        }
+        self.hours = 23;
+
+        let days = days_from_civil(i32::from(self.full_year()), self.month, self.date);
+        let (year, month, date) = civil_from_days(days - 1);
+        let within = year.rem_euclid(100);
+
+        // The weekday register is one-hot, Sunday being bit 0, so the day before is one bit
+        // lower, wrapping around to Saturday.
+        self.weekday = if self.weekday == Weekday::Sunday as u8 {
+            Weekday::Saturday as u8
+        } else {
+            self.weekday >> 1
+        };
+        self.date = date;
+        self.month = month;
+        self.year = within as u8;
+        self.century = (year - within) as u16;
+    }
+
     fn _set(&mut self, value: (u8, u8, u8, Weekday, u8, Month, CurrentYear)) {
         let (hours, minutes, seconds, weekday, day, month, year) = value;
 
@@ -94,72 +451,144 @@ impl ClockData {
         self.weekday = weekday as u8;
         self.date = day;
         self.month = month as u8;
-        self.year = year.0;
+        self.year = year.two_digit();
+        self.century = year.century();
     }
 }
 
-/// Creates a tuple to hold the current year.
-#[derive(Debug, Default)]
-pub struct CurrentYear(u8);
+impl Display for ClockData {
+    /// Formats as ISO 8601, e.g. `2024-10-07T00:00:00.00`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_format(f, Format::Iso8601)
+    }
+}
 
-impl CurrentYear {
-    /// Provides the current year as a [`u8`].
-    pub fn new(value: u16) -> Self {
-        if value < 1970 {
-            panic!("Year must be greater than 1970");
-        }
+/// Layout of [`ClockData::format_into`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// ISO 8601, to the hundredth of a second, e.g. `2024-10-07T00:00:00.00`
+    Iso8601,
+    /// RFC 2822, without a time zone, e.g. `Mon, 07 Oct 2024 00:00:00`; the weekday is derived
+    /// from the date
+    Rfc2822,
+    /// Two-digit year, then month, date, hours, minutes and seconds, e.g. `241007000000`
+    Compact,
+}
 
-        if value < 2000 {
-            Self((value - 1900) as u8)
-        } else {
-            Self((value - 2000) as u8)
+const WEEKDAY_ABBREVIATIONS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// [`ClockData`] does not hold a valid date and time; names the first field out of range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub enum InvalidDateTime {
+    /// Hundredths not within `0..=99`
+    Hundredths,
+    /// Seconds not within `0..=59`
+    Seconds,
+    /// Minutes not within `0..=59`
+    Minutes,
+    /// Hours not within `0..=23`
+    Hours,
+    /// Weekday not a single [`Weekday`] register bit
+    Weekday,
+    /// Date not within the days of the month
+    Date,
+    /// Month not within `1..=12`
+    Month,
+    /// Year not within the supported range
+    Year,
+}
+
+impl InvalidDateTime {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Hundredths => "hundredths out of range",
+            Self::Seconds => "seconds out of range",
+            Self::Minutes => "minutes out of range",
+            Self::Hours => "hours out of range",
+            Self::Weekday => "weekday out of range",
+            Self::Date => "date not within the days of the month",
+            Self::Month => "month out of range",
+            Self::Year => "year not within the supported range",
         }
     }
 }
 
-impl defmt::Format for LoggableClockData {
+impl core::fmt::Display for InvalidDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for InvalidDateTime {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for InvalidDateTime {
     fn format(&self, fmt: defmt::Formatter) {
-        let data = self.data();
+        defmt::write!(fmt, "{=str}", self.as_str());
+    }
+}
 
-        let mut buf = [0u8; 2];
-        let mut buf = ByteMutWriter::new(&mut buf[..]);
-        let hours = left_pad(&mut buf, data.hours);
+/// Creates a tuple to hold the current year.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CurrentYear(u16);
 
-        let mut buf = [0u8; 2];
-        let mut buf = ByteMutWriter::new(&mut buf[..]);
-        let minutes = left_pad(&mut buf, data.minutes);
+impl Default for CurrentYear {
+    fn default() -> Self {
+        Self(2000)
+    }
+}
+
+impl CurrentYear {
+    /// Provides the current year, e.g. `2024`.
+    ///
+    /// The year is not checked here; [`DateTimeBuilder::try_build`] rejects years outside
+    /// 1970 to 2199.
+    #[must_use]
+    pub fn new(value: u16) -> Self {
+        Self(value)
+    }
 
-        let mut buf = [0u8; 2];
-        let mut buf = ByteMutWriter::new(&mut buf[..]);
-        let seconds = left_pad(&mut buf, data.seconds);
+    /// Full year.
+    #[must_use]
+    pub fn get(&self) -> u16 {
+        self.0
+    }
 
-        let mut buf = [0u8; 2];
-        let mut buf = ByteMutWriter::new(&mut buf[..]);
-        let day = left_pad(&mut buf, data.date);
+    /// Two-digit year, as stored by the rtc.
+    #[allow(clippy::cast_possible_truncation)]
+    fn two_digit(self) -> u8 {
+        (self.0 % 100) as u8
+    }
 
-        let month = Month::from(data.month);
-        let weekday = Weekday::from(data.weekday);
+    /// Century of the year.
+    fn century(self) -> u16 {
+        self.0 - self.0 % 100
+    }
+}
 
-        let mut buf = [0u8; 4];
-        let mut buf = ByteMutWriter::new(&mut buf[..]);
-        let year = pad_year(&mut buf, data.year, self.century());
+#[cfg(feature = "defmt")]
+impl defmt::Format for LoggableClockData {
+    fn format(&self, fmt: defmt::Formatter) {
+        let mut buf = [0u8; 32];
 
-        defmt::write!(
-            fmt,
-            "{}:{}:{}, {}, {} {} {}",
-            hours,
-            minutes,
-            seconds,
-            weekday,
-            day,
-            month,
-            year,
-        );
+        match self.data().format_into(&mut buf, Format::Iso8601) {
+            Ok(formatted) => defmt::write!(fmt, "{=str}", formatted),
+            Err(_) => defmt::write!(fmt, "{}", self.data()),
+        }
     }
 }
 
 /// Enumerated type values for the weekday register.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[allow(dead_code)]
 pub enum Weekday {
     #[default]
@@ -188,6 +617,15 @@ pub enum Weekday {
 #[allow(dead_code)]
 #[allow(clippy::match_same_arms)]
 impl Weekday {
+    /// Day of the week of the date, in the proleptic Gregorian calendar; the date is not
+    /// validated.
+    #[must_use]
+    pub fn from_date(year: i32, month: u8, date: u8) -> Self {
+        use crate::calendar::{days_from_civil, weekday_from_days};
+
+        Self::from(1 << weekday_from_days(days_from_civil(year, month, date)))
+    }
+
     /// Get variant from a provided value.
     #[must_use]
     pub fn from(val: u8) -> Self {
@@ -202,29 +640,34 @@ impl Weekday {
             _ => Self::Sunday,
         }
     }
+
+    #[cfg(any(feature = "defmt", feature = "ufmt"))]
+    /// English name of the day, e.g. `Monday`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+        }
+    }
 }
 
+#[cfg(feature = "defmt")]
 impl defmt::Format for Weekday {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(
-            fmt,
-            "{}",
-            match self {
-                Self::Sunday => "Sunday",
-                Self::Monday => "Monday",
-                Self::Tuesday => "Tuesday",
-                Self::Wednesday => "Wednesday",
-                Self::Thursday => "Thursday",
-                Self::Friday => "Friday",
-                Self::Saturday => "Saturday",
-            }
-        );
+        defmt::write!(fmt, "{=str}", self.name());
     }
 }
 
 /// Enumerated type values for the month register.
 #[allow(dead_code)]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 pub enum Month {
     #[default]
     /// January
@@ -275,44 +718,31 @@ impl Month {
             _ => Self::January,
         }
     }
-}
 
-impl defmt::Format for Month {
-    fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(
-            fmt,
-            "{}",
-            match self {
-                Self::January => "January",
-                Self::February => "February",
-                Self::March => "March",
-                Self::April => "April",
-                Self::May => "May",
-                Self::June => "June",
-                Self::July => "July",
-                Self::August => "August",
-                Self::September => "September",
-                Self::October => "October",
-                Self::November => "November",
-                Self::December => "December",
-            }
-        );
+    #[cfg(any(feature = "defmt", feature = "ufmt"))]
+    /// English name of the month, e.g. `October`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::January => "January",
+            Self::February => "February",
+            Self::March => "March",
+            Self::April => "April",
+            Self::May => "May",
+            Self::June => "June",
+            Self::July => "July",
+            Self::August => "August",
+            Self::September => "September",
+            Self::October => "October",
+            Self::November => "November",
+            Self::December => "December",
+        }
     }
 }
 
-/// Enumerated type values for the year.
-#[derive(Debug, Copy, Clone)]
-#[allow(dead_code)]
-pub enum Year {
-    /// 20th Century Fox. Definitely, better times.
-    TwentiethCentury(u8),
-    /// Reality.
-    TwentyFirstCentury(u8),
-}
-
-impl Default for Year {
-    fn default() -> Self {
-        Self::TwentyFirstCentury(20)
+#[cfg(feature = "defmt")]
+impl defmt::Format for Month {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{=str}", self.name());
     }
 }
 
@@ -323,7 +753,7 @@ pub struct DateTimeBuilder {
     minutes: u8,
     seconds: u8,
     date: u8,
-    weekday: Weekday,
+    weekday: Option<Weekday>,
     month: Month,
     year: CurrentYear,
 }
@@ -337,7 +767,7 @@ impl DateTimeBuilder {
             minutes: 0,
             seconds: 0,
             date: 1,
-            weekday: Weekday::Thursday,
+            weekday: None,
             month: Month::January,
             year: CurrentYear::new(1970),
         }
@@ -371,10 +801,10 @@ impl DateTimeBuilder {
         self
     }
 
-    /// Set the weekday.
+    /// Override the weekday, which is otherwise derived from the date.
     #[must_use]
     pub fn weekday(mut self, value: Weekday) -> Self {
-        self.weekday = value;
+        self.weekday = Some(value);
         self
     }
 
@@ -392,15 +822,32 @@ impl DateTimeBuilder {
         self
     }
 
-    /// Build the time.
+    /// Build the time, checking that every field is within range.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first field found out of range, as an [`InvalidDateTime`]: the length of the
+    /// month allows for leap years, and the year must be within 1970 to 2199.
+    pub fn try_build(self) -> Result<ClockData, InvalidDateTime> {
+        let data = self.build();
+        data.validate()?;
+
+        Ok(data)
+    }
+
+    /// Build the time, without checking the fields; see [`DateTimeBuilder::try_build`].
     #[must_use]
     pub fn build(self) -> ClockData {
+        let weekday = self.weekday.unwrap_or_else(|| {
+            Weekday::from_date(i32::from(self.year.get()), self.month as u8, self.date)
+        });
+
         let mut data = ClockData::new();
         data._set((
             self.hours,
             self.minutes,
             self.seconds,
-            self.weekday,
+            weekday,
             self.date,
             self.month,
             self.year,
@@ -410,29 +857,181 @@ impl DateTimeBuilder {
     }
 }
 
-fn left_pad<'a>(buf: &'a mut ByteMutWriter<'_>, value: u8) -> &'a str {
-    buf.clear();
-    write!(buf, "{}{}", common_padding(value), value).unwrap();
+#[cfg(test)]
+mod tests {
+    use super::{
+        ClockData, CurrentYear, DateTimeBuilder, Format, InvalidDateTime, Month, Weekday,
+        MAX_UNIX_TIMESTAMP, MIN_UNIX_TIMESTAMP,
+    };
 
-    buf.as_str()
-}
+    #[test]
+    fn converts_unix_timestamps() {
+        let data = ClockData::from_unix_timestamp_millis(1_728_304_496_789).unwrap();
+        assert_eq!((data.year(), data.month(), data.date()), (24, 10, 7));
+        assert_eq!((data.hours(), data.minutes(), data.seconds()), (12, 34, 56));
+        assert_eq!(data.hundredths(), 78);
+        assert_eq!(data.weekday(), Weekday::Monday as u8);
+
+        assert_eq!(data.to_unix_timestamp(), Ok(1_728_304_496));
+        assert_eq!(data.to_unix_timestamp_millis(), Ok(1_728_304_496_780));
+    }
+
+    #[test]
+    fn bounds_unix_timestamps_to_supported_range() {
+        let first = ClockData::from_unix_timestamp(MIN_UNIX_TIMESTAMP).unwrap();
+        assert_eq!((first.year(), first.month(), first.date()), (0, 1, 1));
+        assert_eq!(first.to_unix_timestamp(), Ok(MIN_UNIX_TIMESTAMP));
 
-fn pad_year<'a>(buf: &'a mut ByteMutWriter<'_>, value: u8, century: Year) -> &'a str {
-    buf.clear();
+        let last = ClockData::from_unix_timestamp(MAX_UNIX_TIMESTAMP).unwrap();
+        assert_eq!((last.year(), last.month(), last.date()), (99, 12, 31));
+        assert_eq!(last.to_unix_timestamp(), Ok(MAX_UNIX_TIMESTAMP));
 
-    match century {
-        Year::TwentiethCentury(_) => write!(buf, "19{}{}", common_padding(value), value).unwrap(),
-        Year::TwentyFirstCentury(_) => write!(buf, "20{}{}", common_padding(value), value).unwrap(),
+        assert!(ClockData::from_unix_timestamp(MIN_UNIX_TIMESTAMP - 1).is_none());
+        assert!(ClockData::from_unix_timestamp(MAX_UNIX_TIMESTAMP + 1).is_none());
+        assert_eq!(
+            ClockData::new().to_unix_timestamp(),
+            Err(InvalidDateTime::Month)
+        );
     }
 
-    buf.as_str()
-}
+    #[test]
+    fn try_build_names_the_invalid_field() {
+        let builder = || {
+            DateTimeBuilder::new()
+                .year(CurrentYear::new(2024))
+                .month(Month::February)
+                .date(29)
+        };
+        let data = builder().hours(23).minutes(59).seconds(59).try_build();
+        assert!(data.is_ok_and(|data| data.validate().is_ok()));
+
+        let data = builder().year(CurrentYear::new(1996)).try_build().unwrap();
+        assert_eq!(
+            (data.century(), data.year(), data.full_year()),
+            (1900, 96, 1996)
+        );
+
+        assert_eq!(
+            builder().hours(24).try_build().err(),
+            Some(InvalidDateTime::Hours)
+        );
+        assert_eq!(
+            builder().minutes(60).try_build().err(),
+            Some(InvalidDateTime::Minutes)
+        );
+        assert_eq!(
+            builder().seconds(60).try_build().err(),
+            Some(InvalidDateTime::Seconds)
+        );
+        assert_eq!(
+            builder().year(CurrentYear::new(2023)).try_build().err(),
+            Some(InvalidDateTime::Date)
+        );
+        assert_eq!(
+            builder().month(Month::April).date(31).try_build().err(),
+            Some(InvalidDateTime::Date)
+        );
+        assert_eq!(
+            builder().year(CurrentYear::new(1969)).try_build().err(),
+            Some(InvalidDateTime::Year)
+        );
+        assert_eq!(
+            builder().year(CurrentYear::new(2200)).try_build().err(),
+            Some(InvalidDateTime::Year)
+        );
+    }
+
+    #[test]
+    fn derives_weekday_unless_overridden() {
+        let builder = || {
+            DateTimeBuilder::new()
+                .year(CurrentYear::new(2024))
+                .month(Month::October)
+                .date(7)
+        };
+
+        let data = builder().build();
+        assert_eq!(data.weekday(), Weekday::Monday as u8);
+        assert_eq!(data.weekday_checked(), Ok(Weekday::Monday));
+
+        let data = builder().weekday(Weekday::Friday).build();
+        assert_eq!(data.weekday(), Weekday::Friday as u8);
+        assert_eq!(data.weekday_checked().err(), Some(InvalidDateTime::Weekday));
+
+        assert_eq!(
+            DateTimeBuilder::new().build().weekday(),
+            Weekday::Thursday as u8
+        );
+    }
 
-fn common_padding<'a>(value: u8) -> &'a str {
-    if value < 10 {
-        "0"
-    } else {
-        ""
+    #[test]
+    fn validate_rejects_register_garbage() {
+        let data = DateTimeBuilder::new().build();
+        assert_eq!(data.validate(), Ok(()));
+
+        let mut garbage = data;
+        garbage.weekday = 0b0000_0011;
+        assert_eq!(garbage.validate(), Err(InvalidDateTime::Weekday));
+
+        let mut garbage = data;
+        garbage.hundredths = 100;
+        assert_eq!(garbage.validate(), Err(InvalidDateTime::Hundredths));
+
+        let mut garbage = data;
+        garbage.month = 2;
+        garbage.date = 30;
+        assert_eq!(garbage.validate(), Err(InvalidDateTime::Date));
+    }
+
+    #[test]
+    fn steps_back_a_minute_across_the_year() {
+        let mut data = DateTimeBuilder::new()
+            .year(CurrentYear::new(2100))
+            .month(Month::January)
+            .date(1)
+            .try_build()
+            .unwrap();
+        assert_eq!(data.weekday(), Weekday::Friday as u8);
+
+        data.previous_minute();
+        assert_eq!(data.to_string(), "2099-12-31T23:59:00.00");
+        assert_eq!(data.weekday(), Weekday::Thursday as u8);
+
+        data.weekday = Weekday::Sunday as u8;
+        data.minutes = 0;
+        data.hours = 0;
+        data.previous_minute();
+        assert_eq!(data.to_string(), "2099-12-30T23:59:00.00");
+        assert_eq!(data.weekday(), Weekday::Saturday as u8);
+    }
+
+    #[test]
+    fn formats_date_and_time() {
+        let data = DateTimeBuilder::new()
+            .year(CurrentYear::new(2024))
+            .month(Month::October)
+            .date(7)
+            .hours(9)
+            .minutes(5)
+            .seconds(3)
+            .try_build()
+            .unwrap();
+
+        assert_eq!(data.to_string(), "2024-10-07T09:05:03.00");
+
+        let mut buf = [0u8; 25];
+        assert_eq!(
+            data.format_into(&mut buf, Format::Rfc2822),
+            Ok("Mon, 07 Oct 2024 09:05:03")
+        );
+        assert_eq!(
+            data.format_into(&mut buf, Format::Compact),
+            Ok("241007090503")
+        );
+        assert!(data.format_into(&mut buf[..21], Format::Iso8601).is_err());
+        assert!(ClockData::new()
+            .format_into(&mut buf, Format::Rfc2822)
+            .is_err());
     }
 }
 
@@ -0,0 +1,213 @@
+//! Parsing of [`ClockData`] from ISO 8601 and compact strings.
+
+use crate::models::{ClockData, InvalidDateTime, Weekday, DEFAULT_CENTURY};
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+/// Length of `YYYY-MM-DDThh:mm:ss`, without a fraction of a second.
+const ISO8601_LEN: usize = 19;
+
+/// Length of `YYMMDDhhmmss`.
+const COMPACT_LEN: usize = 12;
+
+/// A string could not be parsed as [`ClockData`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string is in none of the supported layouts
+    Layout,
+    /// The string is well formed, but does not hold a valid date and time
+    InvalidDateTime(InvalidDateTime),
+}
+
+impl From<InvalidDateTime> for ParseError {
+    fn from(other: InvalidDateTime) -> Self {
+        Self::InvalidDateTime(other)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Layout => f.write_str("expected YYYY-MM-DDThh:mm:ss[.ff] or YYMMDDhhmmss"),
+            Self::InvalidDateTime(field) => write!(f, "invalid date and time: {field}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Layout => None,
+            Self::InvalidDateTime(field) => Some(field),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ParseError {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            Self::Layout => {
+                defmt::write!(fmt, "expected YYYY-MM-DDThh:mm:ss[.ff] or YYMMDDhhmmss");
+            }
+            Self::InvalidDateTime(field) => defmt::write!(fmt, "invalid date and time: {}", field),
+        }
+    }
+}
+
+impl ClockData {
+    /// Parses an ISO 8601 date and time, `YYYY-MM-DDThh:mm:ss`, optionally followed by a
+    /// fraction of a second, e.g. `2024-10-07T12:34:56.78`. The fraction is truncated to
+    /// hundredths; the weekday is derived from the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Layout`] should the string not be in this layout, or
+    /// [`ParseError::InvalidDateTime`] should it not hold a valid date and time, as per
+    /// [`ClockData::validate`].
+    pub fn parse_iso8601(s: &str) -> Result<Self, ParseError> {
+        let bytes = s.as_bytes();
+        let (date_time, fraction) = bytes
+            .split_at_checked(ISO8601_LEN)
+            .ok_or(ParseError::Layout)?;
+
+        let separators = [(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':')];
+        if separators.iter().any(|&(i, c)| date_time[i] != c) {
+            return Err(ParseError::Layout);
+        }
+
+        let hundredths = match fraction {
+            [] => 0,
+            [b'.', digits @ ..] if !digits.is_empty() && digits.iter().all(u8::is_ascii_digit) => {
+                (digits[0] - b'0') * 10 + digits.get(1).map_or(0, |d| d - b'0')
+            }
+            _ => return Err(ParseError::Layout),
+        };
+
+        let year = number(&date_time[0..4])?;
+
+        from_fields(
+            year - year % 100,
+            [
+                (year % 100) as u8,
+                number(&date_time[5..7])? as u8,
+                number(&date_time[8..10])? as u8,
+                number(&date_time[11..13])? as u8,
+                number(&date_time[14..16])? as u8,
+                number(&date_time[17..19])? as u8,
+            ],
+            hundredths,
+        )
+    }
+
+    /// Parses a compact date and time, `YYMMDDhhmmss`, e.g. `241007123456`, in the
+    /// [`DEFAULT_CENTURY`]; the weekday is derived from the date.
+    fn parse_compact(s: &str) -> Result<Self, ParseError> {
+        let bytes = s.as_bytes();
+        if bytes.len() != COMPACT_LEN {
+            return Err(ParseError::Layout);
+        }
+
+        let mut fields = [0; 6];
+        for (field, digits) in fields.iter_mut().zip(bytes.chunks(2)) {
+            *field = number(digits)? as u8;
+        }
+
+        from_fields(DEFAULT_CENTURY, fields, 0)
+    }
+}
+
+impl FromStr for ClockData {
+    type Err = ParseError;
+
+    /// Parses either layout: ISO 8601, as per [`ClockData::parse_iso8601`], or compact,
+    /// `YYMMDDhhmmss` in the [`DEFAULT_CENTURY`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == COMPACT_LEN {
+            Self::parse_compact(s)
+        } else {
+            Self::parse_iso8601(s)
+        }
+    }
+}
+
+/// Parses ASCII decimal digits; at most 4, so that the value fits in a `u16`.
+fn number(digits: &[u8]) -> Result<u16, ParseError> {
+    if digits.is_empty() || digits.len() > 4 || !digits.iter().all(u8::is_ascii_digit) {
+        return Err(ParseError::Layout);
+    }
+
+    Ok(digits
+        .iter()
+        .fold(0, |value, digit| value * 10 + u16::from(digit - b'0')))
+}
+
+/// Creates a validated [`ClockData`] from the year within the century, month, date, hours,
+/// minutes and seconds, deriving the weekday.
+fn from_fields(century: u16, fields: [u8; 6], hundredths: u8) -> Result<ClockData, ParseError> {
+    let [year, month, date, hours, minutes, seconds] = fields;
+
+    let data = ClockData {
+        hundredths,
+        seconds,
+        minutes,
+        hours,
+        weekday: Weekday::from_date(i32::from(century) + i32::from(year), month, date) as u8,
+        date,
+        month,
+        year,
+        century,
+    };
+    data.validate()?;
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+    use crate::models::{ClockData, InvalidDateTime, Weekday};
+
+    #[test]
+    fn parses_iso8601_and_compact() {
+        let data: ClockData = "2024-10-07T12:34:56".parse().unwrap();
+        assert_eq!(data.to_string(), "2024-10-07T12:34:56.00");
+        assert_eq!(data.weekday(), Weekday::Monday as u8);
+
+        let data = ClockData::parse_iso8601("1999-12-31T23:59:59.999").unwrap();
+        assert_eq!(
+            (data.century(), data.year(), data.hundredths()),
+            (1900, 99, 99)
+        );
+
+        let data: ClockData = "241007123456".parse().unwrap();
+        assert_eq!(data.to_string(), "2024-10-07T12:34:56.00");
+    }
+
+    #[test]
+    fn rejects_malformed_and_invalid_strings() {
+        for s in [
+            "",
+            "2024-10-07 12:34:56",
+            "2024-10-07T12:34:56.",
+            "2024-10-07T12:34:5x",
+            "2024-10-07T12:34:56Z",
+            "24100712345x",
+        ] {
+            assert_eq!(
+                s.parse::<ClockData>().unwrap_err(),
+                ParseError::Layout,
+                "{s}"
+            );
+        }
+
+        assert_eq!(
+            "2023-02-29T00:00:00".parse::<ClockData>().unwrap_err(),
+            ParseError::InvalidDateTime(InvalidDateTime::Date)
+        );
+        assert_eq!(
+            "241007243456".parse::<ClockData>().unwrap_err(),
+            ParseError::InvalidDateTime(InvalidDateTime::Hours)
+        );
+    }
+}
@@ -1,11 +1,30 @@
-use crate::error::DriverError;
-use crate::models::ClockData;
-use crate::rtc::{address::SlaveAddress, registers as ClockRegisters};
+use crate::error::{DriverError, InvalidInput};
+use crate::models::{misc::bcd_to_dec, ClockData, DEFAULT_CENTURY};
+use crate::rtc::{
+    address::SlaveAddress,
+    alarm::Alarm,
+    bitfields::{ControlReg, EventControlReg, ExtensionReg, FlagReg},
+    clkout::ClockOutFrequency,
+    event::EventInput,
+    periodic::UpdatePeriod,
+    ram::RamValue,
+    registers::{self as ClockRegisters, Register},
+    timer::{ticks_from_registers, CountdownTimer, TimerClock},
+};
 use core::marker::PhantomData;
 use embedded_hal::i2c::{I2c, SevenBitAddress};
+use embedded_hal_async::i2c::I2c as AsyncI2c;
 
 pub mod address;
+pub mod alarm;
+pub mod bitfields;
+pub mod clkout;
+pub mod event;
+pub mod offset;
+pub mod periodic;
+pub mod ram;
 pub mod registers;
+pub mod timer;
 
 /// Used to fetch latest readings.
 pub mod now;
@@ -30,113 +49,1370 @@ impl AddressingMode for SevenBitAddress {
 pub struct Driver<I2C, A> {
     addr: u8,
     i2c: I2C,
+    check_integrity: bool,
+    verify_writes: bool,
+    century: u16,
+    century_in_ram: bool,
+    last_year: Option<u8>,
     _addr_mode: core::marker::PhantomData<A>,
 }
 
-impl<I2C, A> Driver<I2C, A>
-where
-    I2C: I2c<A::Mode>,
-    I2C::Error: Into<DriverError<I2C::Error>>,
-    A: AddressingMode<Mode = SevenBitAddress> + embedded_hal::i2c::AddressMode,
-{
-    /// Creates a new driver from an I2C peripheral.
-    pub fn new(i2c: I2C) -> Self {
-        Driver {
-            addr: SlaveAddress::Default.into(),
-            i2c,
-            _addr_mode: PhantomData,
+/// Async Driver for the `rv8803` rtc chip.
+///
+/// Refer [`Driver`] for details.
+pub struct DriverAsync<I2C, A> {
+    addr: u8,
+    i2c: I2C,
+    check_integrity: bool,
+    verify_writes: bool,
+    century: u16,
+    century_in_ram: bool,
+    last_year: Option<u8>,
+    _addr_mode: core::marker::PhantomData<A>,
+}
+
+/// Implements [`Driver`] and [`DriverAsync`] from a single definition, so that every feature
+/// lands in both.
+///
+/// The blocking and async halves of the driver, its register access and the `Readable` and
+/// `Updatable` traits are all generated this way: `$maybe_await` is empty for blocking I2C,
+/// and `.await` for async I2C, whilst `$async` is only given for the latter. The futures of the
+/// async traits are not required to be `Send`, as executors on embedded targets are
+/// single-threaded.
+macro_rules! impl_driver {
+    (
+        $driver:ident, $i2c:ident, $readable:ident, $updatable:ident, $registers:ident,
+        $registers_ty:ident, [$($maybe_await:tt)*] $(, $async:tt)?
+    ) => {
+        impl<I2C, A> $driver<I2C, A>
+        where
+            I2C: $i2c<A::Mode>,
+            I2C::Error: Into<DriverError<I2C::Error>>,
+            A: AddressingMode<Mode = SevenBitAddress> + embedded_hal::i2c::AddressMode,
+        {
+            /// Creates a new driver from an I2C peripheral.
+            pub fn new(i2c: I2C) -> Self {
+                Self {
+                    addr: SlaveAddress::Default.into(),
+                    i2c,
+                    check_integrity: false,
+                    verify_writes: false,
+                    century: DEFAULT_CENTURY,
+                    century_in_ram: false,
+                    last_year: None,
+                    _addr_mode: PhantomData,
+                }
+            }
+
+            /// Change I2C address
+            pub fn set_address(&mut self, addr: SlaveAddress) -> u8 {
+                self.addr = addr.into();
+                self.addr
+            }
+
+            /// When enabled, [`Self::now`] returns [`DriverError::ClockIntegrityLost`] whilst V2F
+            /// is set, rather than a reading that may be garbage. Disabled by default.
+            ///
+            /// Clear V2F with [`Self::clear_flags`] once the time has been set.
+            pub fn set_integrity_check(&mut self, enabled: bool) {
+                self.check_integrity = enabled;
+            }
+
+            /// When enabled, every read-modify-write of a register is read back, returning
+            /// [`DriverError::VerifyFailed`] should the rtc not hold the value written. Disabled by
+            /// default.
+            pub fn set_write_verification(&mut self, enabled: bool) {
+                self.verify_writes = enabled;
+            }
+
+            /// release resources
+            pub fn free(self) -> I2C {
+                self.i2c
+            }
+
+            /// Register access, verifying writes as per [`Self::set_write_verification`].
+            fn registers(&self) -> ClockRegisters::$registers_ty {
+                ClockRegisters::$registers(self.addr).with_verify(self.verify_writes)
+            }
+
+            /// Century of the two-digit year held by the rtc, e.g. `2000`.
+            pub fn century(&self) -> u16 {
+                self.century
+            }
+
+            /// Set the century of the two-digit year held by the rtc, e.g. `2000`; rounded down
+            /// to a multiple of 100. Defaults to [`DEFAULT_CENTURY`].
+            ///
+            /// Readings carry this century, which advances whenever the year is seen to roll
+            /// over from 99 to 00. [`Self::update`] sets it from the data written.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidInput`] if the century is not
+            /// within 1900 to 3400. The century is left as is on any error.
+            pub $($async)? fn set_century(
+                &mut self,
+                century: u16,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let century = century - century % 100;
+                if ram::century_to_payload(century).is_none() {
+                    return Err(DriverError::InvalidInput(InvalidInput::Century));
+                }
+
+                self.persist_century(century)$($maybe_await)*?;
+                self.century = century;
+                self.last_year = None;
+
+                Ok(())
+            }
+
+            /// When enabled, the century is kept in the byte of user RAM whenever it changes, so
+            /// that it may be restored with [`Self::restore_century`] after the MCU resets. User
+            /// RAM is then unavailable for other use. Disabled by default.
+            ///
+            /// A rollover whilst the driver is not reading the time goes unnoticed.
+            pub fn set_century_in_ram(&mut self, enabled: bool) {
+                self.century_in_ram = enabled;
+            }
+
+            /// Restore the century kept in user RAM, returning it; or `None`, leaving the
+            /// century as is, if user RAM does not hold a century, e.g. after a voltage drop.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn restore_century(
+                &mut self,
+            ) -> Result<Option<u16>, DriverError<I2C::Error>> {
+                let payload = self.read_ram_checked::<u8>()$($maybe_await)*?;
+
+                if let Some(payload) = payload {
+                    self.century = ram::century_from_payload(payload);
+                    self.last_year = None;
+                }
+
+                Ok(payload.map(|_| self.century))
+            }
+
+            /// Writes the century to user RAM, if enabled.
+            $($async)? fn persist_century(
+                &mut self,
+                century: u16,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                if !self.century_in_ram {
+                    return Ok(());
+                }
+
+                let payload = ram::century_to_payload(century)
+                    .ok_or(DriverError::InvalidInput(InvalidInput::Century))?;
+
+                self.write_ram_checked(&payload)$($maybe_await)*
+            }
+
+            /// Fetch the latest reading from the rtc module, with the century as per
+            /// [`Self::century`]. Should the year be lower than at the last reading, it has rolled
+            /// over and the century advances, e.g. from 2099 to 2100.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::ClockIntegrityLost`] if the integrity
+            /// check is enabled and V2F is set.
+            pub $($async)? fn now(
+                &mut self,
+                mut rtc_chip: impl crate::rtc::now::$readable,
+            ) -> Result<ClockData, DriverError<I2C::Error>>
+            where
+                // T: crate::rtc::now::Read,
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                if self.check_integrity && self.status()$($maybe_await)*?.v2f() {
+                    return Err(DriverError::ClockIntegrityLost);
+                }
+
+                let mut data = crate::models::ClockData::new();
+
+                // Associated instance on T, not to be confused with the value data above.
+                rtc_chip.now(&mut self.i2c, self.addr, &mut data)$($maybe_await)*?;
+
+                // Neither the century nor the last year change unless the new century is kept,
+                // so that a failed write is retried, rather than the rollover counted twice.
+                if self.last_year.is_some_and(|last| data.year < last) {
+                    let century = self.century.saturating_add(100);
+                    self.persist_century(century)$($maybe_await)*?;
+                    self.century = century;
+                }
+                self.last_year = Some(data.year);
+                data.century = self.century;
+
+                Ok(data)
+            }
+
+            /// Update the rtc module. The data is validated before anything is written, and its
+            /// century becomes [`Self::century`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] naming the first field
+            /// out of range, as per [`ClockData::validate`].
+            pub $($async)? fn update(
+                &mut self,
+                mut rtc_chip: impl crate::rtc::update::$updatable,
+                data: &Option<ClockData>,
+            ) -> Result<impl crate::rtc::update::$updatable, DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                let mut cu = self.registers();
+
+                if let Some(d) = data {
+                    d.validate().map_err(DriverError::InvalidDateTime)?;
+                    rtc_chip
+                        .set_datetime(&mut self.i2c, self.addr, &mut cu, d)
+                        $($maybe_await)*?;
+
+                    self.century = d.century;
+                    self.last_year = Some(d.year);
+                    self.persist_century(d.century)$($maybe_await)*?;
+                }
+                Ok(rtc_chip)
+            }
+
+            /// Fetch the latest reading as seconds since the UNIX epoch.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] if the rtc does not hold
+            /// a valid date and time.
+            pub $($async)? fn unix_timestamp(&mut self) -> Result<u64, DriverError<I2C::Error>> {
+                let data = self.now(ClockData::new())$($maybe_await)*?;
+
+                data.to_unix_timestamp().map_err(DriverError::InvalidDateTime)
+            }
+
+            /// Fetch the latest reading as milliseconds since the UNIX epoch, with a resolution
+            /// of hundredths of a second.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] if the rtc does not hold
+            /// a valid date and time.
+            pub $($async)? fn unix_timestamp_millis(
+                &mut self,
+            ) -> Result<u64, DriverError<I2C::Error>> {
+                let data = self.now(ClockData::new())$($maybe_await)*?;
+
+                data.to_unix_timestamp_millis().map_err(DriverError::InvalidDateTime)
+            }
+
+            /// Set the date and time from seconds since the UNIX epoch. The weekday is derived
+            /// from the date.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidInput`] unless the
+            /// timestamp is within the years 2000 to 2099.
+            pub $($async)? fn set_unix_timestamp(
+                &mut self,
+                seconds: u64,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let data = ClockData::from_unix_timestamp(seconds)
+                    .ok_or(DriverError::InvalidInput(InvalidInput::Timestamp))?;
+                self.update(ClockData::new(), &Some(data))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Set the date and time from milliseconds since the UNIX epoch.
+            ///
+            /// The hundredths register cannot be written, and is cleared as the time is set; the
+            /// sub-second part of the timestamp is therefore discarded.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidInput`] unless the
+            /// timestamp is within the years 2000 to 2099.
+            pub $($async)? fn set_unix_timestamp_millis(
+                &mut self,
+                millis: u64,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.set_unix_timestamp(millis / 1000)$($maybe_await)*
+            }
+
+            /// Fetch the latest reading as a [`chrono::NaiveDateTime`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] if the rtc does not hold
+            /// a valid date and time.
+            #[cfg(feature = "chrono")]
+            pub $($async)? fn now_naive(
+                &mut self,
+            ) -> Result<chrono::NaiveDateTime, DriverError<I2C::Error>> {
+                let data = self.now(ClockData::new())$($maybe_await)*?;
+
+                chrono::NaiveDateTime::try_from(data).map_err(DriverError::InvalidDateTime)
+            }
+
+            /// Set the date and time from a [`chrono::NaiveDateTime`]. The weekday is derived from
+            /// the date, and the century becomes [`Self::century`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] if the year is not
+            /// within 1970 to 2199.
+            #[cfg(feature = "chrono")]
+            pub $($async)? fn set_naive(
+                &mut self,
+                value: chrono::NaiveDateTime,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.update(ClockData::new(), &Some(ClockData::from(value)))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Fetch the latest reading as a [`time::PrimitiveDateTime`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] if the rtc does not hold
+            /// a valid date and time.
+            #[cfg(feature = "time")]
+            pub $($async)? fn now_primitive(
+                &mut self,
+            ) -> Result<time::PrimitiveDateTime, DriverError<I2C::Error>> {
+                let data = self.now(ClockData::new())$($maybe_await)*?;
+
+                time::PrimitiveDateTime::try_from(data).map_err(DriverError::InvalidDateTime)
+            }
+
+            /// Set the date and time from a [`time::PrimitiveDateTime`]. The weekday is derived
+            /// from the date, and the century becomes [`Self::century`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidDateTime`] if the year is not
+            /// within 1970 to 2199.
+            #[cfg(feature = "time")]
+            pub $($async)? fn set_primitive(
+                &mut self,
+                value: time::PrimitiveDateTime,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.update(ClockData::new(), &Some(ClockData::from(value)))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Fetch the status flags, i.e. the Flag register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn status(&mut self) -> Result<FlagReg, DriverError<I2C::Error>> {
+                self.read_flag()$($maybe_await)*
+            }
+
+            /// Clear every status flag, releasing the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn clear_flags(&mut self) -> Result<(), DriverError<I2C::Error>> {
+                self.write_flag(FlagReg::new(0))$($maybe_await)*
+            }
+
+            /// Read the Extension register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_extension(
+                &mut self,
+            ) -> Result<ExtensionReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.read_bitfield(&mut self.i2c)$($maybe_await)*
+            }
+
+            /// Write the Extension register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn write_extension(
+                &mut self,
+                value: ExtensionReg,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.write_bitfield(&mut self.i2c, value)$($maybe_await)*
+            }
+
+            /// Read-modify-write the Extension register, returning the value written.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn modify_extension<F: FnOnce(&mut ExtensionReg)>(
+                &mut self,
+                modify: F,
+            ) -> Result<ExtensionReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.modify_bitfield(&mut self.i2c, modify)$($maybe_await)*
+            }
+
+            /// Read the Flag register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_flag(&mut self) -> Result<FlagReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.read_bitfield(&mut self.i2c)$($maybe_await)*
+            }
+
+            /// Write the Flag register: flags written as 0 are cleared, whilst those written as 1
+            /// are left as is.
+            ///
+            /// There is no read-modify-write of the Flag register, as it would clear any flag
+            /// raised in between.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn write_flag(
+                &mut self,
+                value: FlagReg,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.write_bitfield(&mut self.i2c, value)$($maybe_await)*
+            }
+
+            /// Read the Control register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_control(
+                &mut self,
+            ) -> Result<ControlReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.read_bitfield(&mut self.i2c)$($maybe_await)*
+            }
+
+            /// Write the Control register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn write_control(
+                &mut self,
+                value: ControlReg,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.write_bitfield(&mut self.i2c, value)$($maybe_await)*
+            }
+
+            /// Read-modify-write the Control register, returning the value written.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn modify_control<F: FnOnce(&mut ControlReg)>(
+                &mut self,
+                modify: F,
+            ) -> Result<ControlReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.modify_bitfield(&mut self.i2c, modify)$($maybe_await)*
+            }
+
+            /// Read the Event Control register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_event_control(
+                &mut self,
+            ) -> Result<EventControlReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.read_bitfield(&mut self.i2c)$($maybe_await)*
+            }
+
+            /// Write the Event Control register.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn write_event_control(
+                &mut self,
+                value: EventControlReg,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.write_bitfield(&mut self.i2c, value)$($maybe_await)*
+            }
+
+            /// Read-modify-write the Event Control register, returning the value written.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn modify_event_control<F: FnOnce(&mut EventControlReg)>(
+                &mut self,
+                modify: F,
+            ) -> Result<EventControlReg, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.modify_bitfield(&mut self.i2c, modify)$($maybe_await)*
+            }
+
+            /// Read-modify-write any register, returning the value written; for registers
+            /// without a typed accessor. Nothing is written should the read fail, and the write
+            /// is verified as per [`Self::set_write_verification`].
+            ///
+            /// Use [`Self::write_flag`] for the Flag register instead, so as not to clear flags
+            /// raised in between.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn modify_register<F: FnOnce(u8) -> u8>(
+                &mut self,
+                register: Register,
+                modify: F,
+            ) -> Result<u8, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.modify(&mut self.i2c, register, modify)$($maybe_await)*
+            }
+
+            /// Set the alarm. The alarm flag is cleared, whilst the alarm interrupt is left as is.
+            ///
+            /// As per the datasheet, the alarm interrupt is disabled whilst WADA and the alarm
+            /// registers are written, so that a partly written alarm cannot fire; it is then
+            /// restored once the alarm flag is cleared.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn set_alarm(
+                &mut self,
+                alarm: Alarm,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let mut interrupt = false;
+                self.modify_control(|control| {
+                    interrupt = control.aie();
+                    control.set_aie(false);
+                })
+                $($maybe_await)*?;
+                self.modify_extension(|ext| ext.set_wada(alarm.is_date()))$($maybe_await)*?;
+
+                let mut cr = self.registers();
+                cr.write_registers(
+                    &mut self.i2c,
+                    Register::MinutesAlarm,
+                    &alarm.to_registers(),
+                )
+                $($maybe_await)*?;
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_af(false))$($maybe_await)*?;
+
+                if interrupt {
+                    self.modify_control(|control| control.set_aie(true))$($maybe_await)*?;
+                }
+
+                Ok(())
+            }
+
+            /// Fetch the alarm.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn alarm(&mut self) -> Result<Alarm, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                let mut regs = [0; 3];
+                cr.read_registers(&mut self.i2c, Register::MinutesAlarm, &mut regs)
+                    $($maybe_await)*?;
+                let wada = self.read_extension()$($maybe_await)*?.wada();
+
+                Ok(Alarm::from_registers(regs, wada))
+            }
+
+            /// Enable the alarm interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn enable_alarm_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_aie(true))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Disable the alarm interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn disable_alarm_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_aie(false))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Whether the alarm has fired since the alarm flag was last cleared.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn alarm_fired(&mut self) -> Result<bool, DriverError<I2C::Error>> {
+                Ok(self.read_flag()$($maybe_await)*?.af())
+            }
+
+            /// Clear the alarm flag, releasing the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn clear_alarm_flag(&mut self) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_af(false))$($maybe_await)*
+            }
+
+            /// Configure the countdown timer. The timer is stopped and its flag cleared; start it
+            /// with [`Self::start_countdown_timer`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn set_countdown_timer(
+                &mut self,
+                timer: CountdownTimer,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_extension(|ext| ext.set_te(false))$($maybe_await)*?;
+                self.modify_extension(|ext| ext.set_td(timer.clock()))$($maybe_await)*?;
+
+                let mut cr = self.registers();
+                cr.write_registers(
+                    &mut self.i2c,
+                    Register::TimerCounter0,
+                    &timer.to_registers(),
+                )
+                $($maybe_await)*?;
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_tf(false))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Start the countdown timer.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn start_countdown_timer(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_extension(|ext| ext.set_te(true))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Stop the countdown timer.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn stop_countdown_timer(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_extension(|ext| ext.set_te(false))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Fetch the source clock of the countdown timer.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn countdown_timer_clock(
+                &mut self,
+            ) -> Result<TimerClock, DriverError<I2C::Error>> {
+                Ok(self.read_extension()$($maybe_await)*?.td())
+            }
+
+            /// Fetch the preset ticks of the countdown timer, from which it counts down.
+            ///
+            /// The Timer Counter registers read back the preset value, not the live count; see
+            /// [`Self::countdown_timer_remaining`].
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn countdown_timer_preset(
+                &mut self,
+            ) -> Result<u16, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                let mut regs = [0; 2];
+                cr.read_registers(&mut self.i2c, Register::TimerCounter0, &mut regs)
+                    $($maybe_await)*?;
+
+                Ok(ticks_from_registers(regs))
+            }
+
+            /// Fetch the ticks remaining before the countdown timer fires.
+            ///
+            /// # Errors
+            ///
+            /// Always returns [`DriverError::Unsupported`]: the RV-8803 does not expose the live
+            /// count, its Timer Counter registers only reading back the preset value.
+            pub $($async)? fn countdown_timer_remaining(
+                &mut self,
+            ) -> Result<u16, DriverError<I2C::Error>> {
+                Err(DriverError::Unsupported)
+            }
+
+            /// Enable the timer interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn enable_timer_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_tie(true))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Disable the timer interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn disable_timer_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_tie(false))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Whether the countdown timer has fired since the timer flag was last cleared.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn timer_fired(&mut self) -> Result<bool, DriverError<I2C::Error>> {
+                Ok(self.read_flag()$($maybe_await)*?.tf())
+            }
+
+            /// Clear the timer flag, releasing the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn clear_timer_flag(&mut self) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_tf(false))$($maybe_await)*
+            }
+
+            /// Enable the periodic time update interrupt on the INT pin, firing once per `period`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn set_update_interrupt(
+                &mut self,
+                period: UpdatePeriod,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_uie(false))$($maybe_await)*?;
+                self.modify_extension(|ext| ext.set_usel(period.usel()))$($maybe_await)*?;
+
+                let mut cr = self.registers();
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_uf(false))$($maybe_await)*?;
+
+                self.modify_control(|control| control.set_uie(true))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Disable the periodic time update interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn disable_update_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_uie(false))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Whether a periodic time update has occurred since the update flag was last cleared.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn update_fired(&mut self) -> Result<bool, DriverError<I2C::Error>> {
+                Ok(self.read_flag()$($maybe_await)*?.uf())
+            }
+
+            /// Clear the update flag, releasing the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn clear_update_flag(&mut self) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_uf(false))$($maybe_await)*
+            }
+
+            /// Set the frequency of the CLKOUT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn set_clock_output(
+                &mut self,
+                frequency: ClockOutFrequency,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_extension(|ext| ext.set_fd(frequency))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Fetch the frequency of the CLKOUT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn clock_output(
+                &mut self,
+            ) -> Result<ClockOutFrequency, DriverError<I2C::Error>> {
+                Ok(self.read_extension()$($maybe_await)*?.fd())
+            }
+
+            /// Configure the external event input on the EVI pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn configure_event_input(
+                &mut self,
+                config: EventInput,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.write_event_control(config.to_register())$($maybe_await)*
+            }
+
+            /// Fetch the configuration of the external event input.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn event_input(
+                &mut self,
+            ) -> Result<EventInput, DriverError<I2C::Error>> {
+                let value = self.read_event_control()$($maybe_await)*?;
+
+                Ok(EventInput::from_register(value))
+            }
+
+            /// Enable the event interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn enable_event_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_eie(true))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Disable the event interrupt on the INT pin.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn disable_event_interrupt(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_control(|control| control.set_eie(false))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Whether an event has been detected since the event flag was last cleared.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn event_fired(&mut self) -> Result<bool, DriverError<I2C::Error>> {
+                Ok(self.read_flag()$($maybe_await)*?.evf())
+            }
+
+            /// Clear the event flag, releasing the INT pin and re-arming the time stamp.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn clear_event_flag(&mut self) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.clear_flag(&mut self.i2c, |flags| flags.set_evf(false))$($maybe_await)*
+            }
+
+            /// Reset the captured time stamp to zero.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn reset_event_timestamp(
+                &mut self,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.modify_event_control(|event| event.set_erst(true))$($maybe_await)*?;
+
+                Ok(())
+            }
+
+            /// Fetch the time stamp of the last event, or `None` if time stamping is disabled,
+            /// see [`EventInput::capture`], or no event has been detected since the event flag
+            /// was last cleared.
+            ///
+            /// The rtc only captures the hundredths and seconds; the remaining fields are those of
+            /// the current time, less a minute should the captured seconds be later than the
+            /// current ones. This should therefore be called within a minute of the event.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_event_timestamp(
+                &mut self,
+            ) -> Result<Option<ClockData>, DriverError<I2C::Error>> {
+                // Without ECP, the capture registers are not updated on an event.
+                if !self.read_event_control()$($maybe_await)*?.ecp() {
+                    return Ok(None);
+                }
+                if !self.event_fired()$($maybe_await)*? {
+                    return Ok(None);
+                }
+
+                let mut cr = self.registers();
+                let mut regs = [0; 2];
+                cr.read_registers(&mut self.i2c, Register::HundredthsCapture, &mut regs)
+                    $($maybe_await)*?;
+
+                let mut data = self.now(ClockData::new())$($maybe_await)*?;
+                let (hundredths, seconds) = (bcd_to_dec(regs[0]), bcd_to_dec(regs[1]));
+
+                // Captured later in the minute than now, so in the previous minute.
+                if (seconds, hundredths) > (data.seconds, data.hundredths) {
+                    data.previous_minute();
+                }
+                data.hundredths = hundredths;
+                data.seconds = seconds;
+
+                Ok(Some(data))
+            }
+
+            /// Set the frequency offset, in ppm, to compensate for the aging of the crystal.
+            ///
+            /// The offset is rounded to the nearest step of
+            /// [`OFFSET_STEP_PPM`](offset::OFFSET_STEP_PPM), and must be within
+            /// [`MIN_OFFSET_PPM`](offset::MIN_OFFSET_PPM) to
+            /// [`MAX_OFFSET_PPM`](offset::MAX_OFFSET_PPM).
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidInput`] if the offset is out of
+            /// range.
+            pub $($async)? fn set_offset_ppm(
+                &mut self,
+                ppm: f32,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let value = offset::ppm_to_register(ppm)
+                    .ok_or(DriverError::InvalidInput(InvalidInput::Offset))?;
+
+                let mut cr = self.registers();
+                cr.write_register(&mut self.i2c, Register::Offset, value)
+                    $($maybe_await)*
+            }
+
+            /// Fetch the frequency offset, in ppm.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn offset_ppm(&mut self) -> Result<f32, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+                let value = cr.read_register(&mut self.i2c, Register::Offset)$($maybe_await)*?;
+
+                Ok(offset::register_to_ppm(value))
+            }
+
+            /// Read the byte of user RAM.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_ram(&mut self) -> Result<u8, DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.read_register(&mut self.i2c, Register::Ram)$($maybe_await)*
+            }
+
+            /// Write the byte of user RAM.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn write_ram(
+                &mut self,
+                byte: u8,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let mut cr = self.registers();
+
+                cr.write_register(&mut self.i2c, Register::Ram, byte)$($maybe_await)*
+            }
+
+            /// Read a [`RamValue`] from user RAM.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_ram_value<T: RamValue>(
+                &mut self,
+            ) -> Result<T, DriverError<I2C::Error>> {
+                Ok(T::from_byte(self.read_ram()$($maybe_await)*?))
+            }
+
+            /// Write a [`RamValue`] to user RAM.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn write_ram_value<T: RamValue>(
+                &mut self,
+                value: &T,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                self.write_ram(value.to_byte())$($maybe_await)*
+            }
+
+            /// Read a [`RamValue`] written by [`Self::write_ram_checked`], or `None` if its
+            /// checksum does not match, e.g. after the contents were lost to a voltage drop.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            pub $($async)? fn read_ram_checked<T: RamValue>(
+                &mut self,
+            ) -> Result<Option<T>, DriverError<I2C::Error>> {
+                Ok(ram::unpack_checked(self.read_ram()$($maybe_await)*?).map(T::from_byte))
+            }
+
+            /// Write a [`RamValue`] to user RAM, together with a checksum nibble. The value is
+            /// limited to 4 bits, i.e. [`RamValue::to_byte`] must be at most `0x0F`.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::InvalidInput`] if the value does not
+            /// fit in 4 bits.
+            pub $($async)? fn write_ram_checked<T: RamValue>(
+                &mut self,
+                value: &T,
+            ) -> Result<(), DriverError<I2C::Error>> {
+                let byte = ram::pack_checked(value.to_byte())
+                    .ok_or(DriverError::InvalidInput(InvalidInput::RamValue))?;
+
+                self.write_ram(byte)$($maybe_await)*
+            }
         }
+    };
+}
+
+impl_driver!(Driver, I2c, Readable, Updatable, new, ClockRegisters, []);
+impl_driver!(
+    DriverAsync,
+    AsyncI2c,
+    ReadableAsync,
+    UpdatableAsync,
+    new_async,
+    ClockRegistersAsync,
+    [.await],
+    async
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        alarm::Alarm,
+        periodic::UpdatePeriod,
+        registers::Register,
+        timer::{CountdownTimer, TimerClock},
+        Driver, DriverAsync, DriverError,
+    };
+    use crate::error::InvalidInput;
+    use crate::models::{DateTimeBuilder, InvalidDateTime};
+    use crate::ClockData;
+    use embedded_hal::i2c::{ErrorKind, SevenBitAddress};
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+
+    const ADDR: u8 = 0x32;
+
+    /// Both drivers share a single definition, and so issue the same transactions.
+    #[test]
+    fn blocking_and_async_drivers_agree() {
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0000_1000]),
+            Transaction::write(ADDR, vec![0x1F, 0b0000_0000]),
+            Transaction::write_read(ADDR, vec![0x1D], vec![0b0000_0000]),
+            Transaction::write(ADDR, vec![0x1D, 0b0100_0000]),
+            Transaction::transaction_start(ADDR),
+            Transaction::write(ADDR, vec![0x08]),
+            Transaction::write(ADDR, vec![0x30, 0x07, 0x15]),
+            Transaction::transaction_end(ADDR),
+            Transaction::write(ADDR, vec![0x1E, 0b0011_0111]),
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0000_0000]),
+            Transaction::write(ADDR, vec![0x1F, 0b0000_1000]),
+        ];
+        let alarm = Alarm::new()
+            .minute(30)
+            .and_then(|alarm| alarm.hour(7))
+            .and_then(|alarm| alarm.date(15))
+            .unwrap();
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_alarm(alarm).unwrap();
+        rtc.free().done();
+
+        let mut rtc: DriverAsync<_, SevenBitAddress> = DriverAsync::new(Mock::new(&expectations));
+        embassy_futures::block_on(rtc.set_alarm(alarm)).unwrap();
+        rtc.free().done();
     }
 
-    /// Change I2C address
-    pub fn set_address(&mut self, addr: SlaveAddress) -> u8 {
-        self.addr = addr.into();
-        self.addr
+    #[test]
+    fn modify_register_verifies_the_write() {
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x0B], vec![0x10]),
+            Transaction::write(ADDR, vec![0x0B, 0x20]),
+            Transaction::write_read(ADDR, vec![0x0B], vec![0x10]),
+        ];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_write_verification(true);
+        assert!(matches!(
+            rtc.modify_register(Register::TimerCounter0, |bits| bits << 1),
+            Err(DriverError::VerifyFailed {
+                register: Register::TimerCounter0,
+                written: 0x20,
+                read: 0x10,
+            })
+        ));
+        rtc.free().done();
     }
 
-    /// release resources
-    pub fn free(self) -> I2C {
-        self.i2c
+    #[test]
+    fn update_rejects_invalid_data_before_writing() {
+        let data = DateTimeBuilder::new().hours(25).build();
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&[]));
+        let result = rtc.update(ClockData::new(), &Some(data));
+        assert!(matches!(
+            result,
+            Err(DriverError::InvalidDateTime(InvalidDateTime::Hours))
+        ));
+        rtc.free().done();
     }
 
-    /// Fetch the latest reading from the rtc module.
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`DriverError`]
-    pub fn now(
-        &mut self,
-        mut rtc_chip: impl crate::rtc::now::Readable,
-    ) -> Result<ClockData, DriverError<I2C::Error>>
-    where
-        // T: crate::rtc::now::Read,
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        let mut data = crate::models::ClockData::new();
-
-        // Associated instance on T, not to be confused with the value data above.
-        rtc_chip.now(&mut self.i2c, self.addr, &mut data)?;
-
-        Ok(data)
+    #[test]
+    fn integrity_check_rejects_a_reading_whilst_v2f_is_set() {
+        let expectations = [Transaction::write_read(ADDR, vec![0x1E], vec![0b0000_0010])];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_integrity_check(true);
+        assert!(matches!(
+            rtc.now(ClockData::new()),
+            Err(DriverError::ClockIntegrityLost)
+        ));
+        rtc.free().done();
     }
 
-    /// Update the rtc module.
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`DriverError`]
-    pub fn update(
-        &mut self,
-        mut rtc_chip: impl crate::rtc::update::Updatable,
-        data: &Option<ClockData>,
-    ) -> Result<impl crate::rtc::update::Updatable, DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        let mut cu = ClockRegisters::new(self.addr);
-
-        if let Some(d) = data {
-            rtc_chip.set_datetime(&mut self.i2c, self.addr, &mut cu, d)?;
-        }
-        Ok(rtc_chip)
+    #[test]
+    fn integrity_check_passes_once_v2f_is_cleared() {
+        let expectations = [
+            Transaction::write(ADDR, vec![0x1E, 0x00]),
+            Transaction::write_read(ADDR, vec![0x1E], vec![0b0000_0001]),
+            Transaction::write_read(
+                ADDR,
+                vec![0x10],
+                vec![0x00, 0x56, 0x34, 0x12, 0x02, 0x07, 0x10, 0x24],
+            ),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x01, 0x56]),
+        ];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_integrity_check(true);
+        rtc.clear_flags().unwrap();
+        let data = rtc.now(ClockData::new()).unwrap();
+        assert_eq!(data.to_string(), "2024-10-07T12:34:56.00");
+        rtc.free().done();
     }
-}
 
-/// Async Driver for the `rv8803` rtc chip.
-/// *WARNING*: This is in progress, and will be completed in a future release.
-#[allow(dead_code)]
-pub struct DriverAsync<I2C, A> {
-    addr: u8,
-    i2c: I2C,
-    _addr_mode: core::marker::PhantomData<A>,
-}
+    #[test]
+    fn countdown_timer_is_stopped_whilst_configured() {
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x1D], vec![0b0001_0001]),
+            Transaction::write(ADDR, vec![0x1D, 0b0000_0001]),
+            Transaction::write_read(ADDR, vec![0x1D], vec![0b0000_0001]),
+            Transaction::write(ADDR, vec![0x1D, 0b0000_0010]),
+            Transaction::transaction_start(ADDR),
+            Transaction::write(ADDR, vec![0x0B]),
+            Transaction::write(ADDR, vec![0x2C, 0x01]),
+            Transaction::transaction_end(ADDR),
+            Transaction::write(ADDR, vec![0x1E, 0b0010_1111]),
+        ];
+        let timer = CountdownTimer::new(TimerClock::Hz1, 300).unwrap();
 
-impl<I2C, A> DriverAsync<I2C, A>
-where
-    I2C: embedded_hal_async::i2c::I2c<A::Mode>,
-    I2C::Error: Into<DriverError<I2C::Error>>,
-    A: AddressingMode<Mode = SevenBitAddress> + embedded_hal_async::i2c::AddressMode,
-{
-    /// Creates a new driver from an I2C peripheral.
-    #[allow(dead_code)]
-    pub fn new(i2c: I2C) -> Self {
-        DriverAsync {
-            addr: SlaveAddress::Default.into(),
-            i2c,
-            _addr_mode: PhantomData,
-        }
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_countdown_timer(timer).unwrap();
+        rtc.free().done();
+    }
+
+    #[test]
+    fn remaining_countdown_ticks_are_unsupported() {
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&[]));
+        assert!(matches!(
+            rtc.countdown_timer_remaining(),
+            Err(DriverError::Unsupported)
+        ));
+        rtc.free().done();
+    }
+
+    #[test]
+    fn update_interrupt_is_disabled_whilst_configured() {
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0010_0000]),
+            Transaction::write(ADDR, vec![0x1F, 0b0000_0000]),
+            Transaction::write_read(ADDR, vec![0x1D], vec![0b0000_0000]),
+            Transaction::write(ADDR, vec![0x1D, 0b0010_0000]),
+            Transaction::write(ADDR, vec![0x1E, 0b0001_1111]),
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0000_0000]),
+            Transaction::write(ADDR, vec![0x1F, 0b0010_0000]),
+        ];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_update_interrupt(UpdatePeriod::Minute).unwrap();
+        rtc.free().done();
     }
 
-    /// Fetch the year value.
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`DriverError`]
-    pub async fn get_year(&mut self, buf: u8) -> Result<(), DriverError<I2C::Error>> {
-        self.i2c.write_read(self.addr, &[0x06], &mut [buf]).await?;
+    #[test]
+    fn century_advances_on_rollover() {
+        let block = |year| vec![0x00, 0x59, 0x59, 0x23, 0x40, 0x31, 0x12, year];
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x10], block(0x99)),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x59]),
+            Transaction::write_read(ADDR, vec![0x10], block(0x00)),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x59]),
+            // Century 2100, i.e. a payload of 2 with its checksum.
+            Transaction::write(ADDR, vec![0x07, 0xD2]),
+        ];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_century_in_ram(true);
+        assert_eq!(rtc.now(ClockData::new()).unwrap().full_year(), 2099);
+        assert_eq!(rtc.now(ClockData::new()).unwrap().full_year(), 2100);
+        assert_eq!(rtc.century(), 2100);
+        rtc.free().done();
+    }
+
+    #[test]
+    fn event_timestamp_crosses_a_minute_boundary() {
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x2F], vec![0b1000_0000]),
+            Transaction::write_read(ADDR, vec![0x1E], vec![0x04]),
+            Transaction::write_read(ADDR, vec![0x20], vec![0x50, 0x58]),
+            Transaction::write_read(
+                ADDR,
+                vec![0x10],
+                vec![0x10, 0x02, 0x00, 0x13, 0x02, 0x07, 0x10, 0x24],
+            ),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x11, 0x02]),
+        ];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        let event = rtc.read_event_timestamp().unwrap().unwrap();
+        assert_eq!(event.to_string(), "2024-10-07T12:59:58.50");
+        rtc.free().done();
+    }
+
+    #[test]
+    fn event_timestamp_needs_capture_enabled() {
+        let expectations = [Transaction::write_read(ADDR, vec![0x2F], vec![0b0000_0000])];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        assert!(rtc.read_event_timestamp().unwrap().is_none());
+        rtc.free().done();
+    }
+
+    #[test]
+    fn century_advances_once_after_a_failed_persist() {
+        let block = |year| vec![0x00, 0x59, 0x59, 0x23, 0x40, 0x31, 0x12, year];
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x10], block(0x99)),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x59]),
+            Transaction::write_read(ADDR, vec![0x10], block(0x00)),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x59]),
+            Transaction::write(ADDR, vec![0x07, 0xD2]).with_error(ErrorKind::Other),
+            Transaction::write_read(ADDR, vec![0x10], block(0x00)),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x59]),
+            Transaction::write(ADDR, vec![0x07, 0xD2]),
+        ];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        rtc.set_century_in_ram(true);
+        assert_eq!(rtc.now(ClockData::new()).unwrap().full_year(), 2099);
+        assert!(rtc.now(ClockData::new()).is_err());
+        assert_eq!(rtc.century(), 2000);
+        assert_eq!(rtc.now(ClockData::new()).unwrap().full_year(), 2100);
+        assert_eq!(rtc.century(), 2100);
+        rtc.free().done();
+    }
+
+    #[test]
+    fn set_century_keeps_the_century_on_error() {
+        let expectations =
+            [Transaction::write(ADDR, vec![0x07, 0xD2]).with_error(ErrorKind::Other)];
+
+        let mut rtc: Driver<_, SevenBitAddress> = Driver::new(Mock::new(&expectations));
+        assert!(matches!(
+            rtc.set_century(3500),
+            Err(DriverError::InvalidInput(InvalidInput::Century))
+        ));
+        assert_eq!(rtc.century(), 2000);
 
-        Ok(())
+        rtc.set_century_in_ram(true);
+        assert!(rtc.set_century(2100).is_err());
+        assert_eq!(rtc.century(), 2000);
+        rtc.free().done();
     }
 }
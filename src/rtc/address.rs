@@ -4,6 +4,7 @@ const DEFAULT_ADDRESS: u8 = 0x32;
 
 /// I2C device address
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(clippy::module_name_repetitions)]
 pub enum SlaveAddress {
     /// Default slave address
@@ -0,0 +1,234 @@
+//! Alarm
+
+use crate::models::{
+    misc::{bcd_to_dec, dec_to_bcd},
+    Weekday,
+};
+
+/// Set in an alarm register to exclude it from the alarm match.
+const ALARM_DISABLED: u8 = 0b1000_0000;
+
+/// Weekday bits of the Weekday Alarm register, Sunday through Saturday.
+const WEEKDAY_MASK: u8 = 0b0111_1111;
+
+/// Weekday or date component of an [`Alarm`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmDay {
+    /// Matches any of the weekdays in the mask, using the [`Weekday`] register bits.
+    Weekdays(u8),
+    /// Matches the date, i.e. the day of the month.
+    Date(u8),
+}
+
+/// Alarm configuration, matched against the minutes, hours and weekday or date.
+///
+/// The alarm fires when every enabled field matches; an alarm with no fields enabled never
+/// fires.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "AlarmFields"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Alarm {
+    minute: Option<u8>,
+    hour: Option<u8>,
+    day: Option<AlarmDay>,
+}
+
+impl Alarm {
+    /// Creates an [`Alarm`] with every field disabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match on the minute. Returns `None` unless the minute is within `0..=59`.
+    #[must_use]
+    pub fn minute(mut self, value: u8) -> Option<Self> {
+        (value <= 59).then(|| {
+            self.minute = Some(value);
+            self
+        })
+    }
+
+    /// Match on the hour. Returns `None` unless the hour is within `0..=23`.
+    #[must_use]
+    pub fn hour(mut self, value: u8) -> Option<Self> {
+        (value <= 23).then(|| {
+            self.hour = Some(value);
+            self
+        })
+    }
+
+    /// Match on a weekday; this may be called repeatedly to match on several weekdays.
+    ///
+    /// Replaces a previously set date.
+    #[must_use]
+    pub fn weekday(mut self, value: Weekday) -> Self {
+        let mask = match self.day {
+            Some(AlarmDay::Weekdays(mask)) => mask,
+            _ => 0,
+        };
+        self.day = Some(AlarmDay::Weekdays(mask | value as u8));
+        self
+    }
+
+    /// Match on any of the weekdays in the mask, using the [`Weekday`] register bits.
+    /// Returns `None` unless the mask holds at least one weekday, and nothing else.
+    ///
+    /// Replaces a previously set date.
+    #[must_use]
+    pub fn weekdays(mut self, mask: u8) -> Option<Self> {
+        (mask != 0 && mask & !WEEKDAY_MASK == 0).then(|| {
+            self.day = Some(AlarmDay::Weekdays(mask));
+            self
+        })
+    }
+
+    /// Match on the date, i.e. the day of the month. Returns `None` unless the date is within
+    /// `1..=31`.
+    ///
+    /// Replaces previously set weekdays.
+    #[must_use]
+    pub fn date(mut self, value: u8) -> Option<Self> {
+        (1..=31).contains(&value).then(|| {
+            self.day = Some(AlarmDay::Date(value));
+            self
+        })
+    }
+
+    /// Minute, if enabled.
+    #[must_use]
+    pub fn get_minute(&self) -> Option<u8> {
+        self.minute
+    }
+
+    /// Hour, if enabled.
+    #[must_use]
+    pub fn get_hour(&self) -> Option<u8> {
+        self.hour
+    }
+
+    /// Weekdays or date, if enabled.
+    #[must_use]
+    pub fn get_day(&self) -> Option<AlarmDay> {
+        self.day
+    }
+
+    /// Encodes the Minutes, Hours and Weekday/Date Alarm registers.
+    pub(crate) fn to_registers(self) -> [u8; 3] {
+        let minute = self
+            .minute
+            .map_or(ALARM_DISABLED, |value| dec_to_bcd(value) & 0x7F);
+        let hour = self
+            .hour
+            .map_or(ALARM_DISABLED, |value| dec_to_bcd(value) & 0x3F);
+        let day = match self.day {
+            Some(AlarmDay::Weekdays(mask)) => mask & WEEKDAY_MASK,
+            Some(AlarmDay::Date(value)) => dec_to_bcd(value) & 0x3F,
+            None => ALARM_DISABLED,
+        };
+
+        [minute, hour, day]
+    }
+
+    /// Decodes the Minutes, Hours and Weekday/Date Alarm registers; `wada` is the WADA bit of
+    /// the Extension register.
+    pub(crate) fn from_registers(regs: [u8; 3], wada: bool) -> Self {
+        let enabled = |value: u8| value & ALARM_DISABLED == 0;
+
+        Self {
+            minute: enabled(regs[0]).then(|| bcd_to_dec(regs[0] & 0x7F)),
+            hour: enabled(regs[1]).then(|| bcd_to_dec(regs[1] & 0x3F)),
+            day: enabled(regs[2]).then(|| {
+                if wada {
+                    AlarmDay::Date(bcd_to_dec(regs[2] & 0x3F))
+                } else {
+                    AlarmDay::Weekdays(regs[2] & WEEKDAY_MASK)
+                }
+            }),
+        }
+    }
+
+    /// Whether the WADA bit is to be set, i.e. the alarm matches on the date.
+    pub(crate) fn is_date(self) -> bool {
+        matches!(self.day, Some(AlarmDay::Date(_)))
+    }
+}
+
+/// Fields of a deserialised [`Alarm`], checked by its builder.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct AlarmFields {
+    minute: Option<u8>,
+    hour: Option<u8>,
+    day: Option<AlarmDay>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<AlarmFields> for Alarm {
+    type Error = &'static str;
+
+    fn try_from(fields: AlarmFields) -> Result<Self, Self::Error> {
+        let mut alarm = Some(Self::new());
+        if let Some(minute) = fields.minute {
+            alarm = alarm.and_then(|alarm| alarm.minute(minute));
+        }
+        if let Some(hour) = fields.hour {
+            alarm = alarm.and_then(|alarm| alarm.hour(hour));
+        }
+        alarm = match fields.day {
+            Some(AlarmDay::Weekdays(mask)) => alarm.and_then(|alarm| alarm.weekdays(mask)),
+            Some(AlarmDay::Date(date)) => alarm.and_then(|alarm| alarm.date(date)),
+            None => alarm,
+        };
+
+        alarm.ok_or("alarm field out of range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alarm, AlarmDay};
+    use crate::models::Weekday;
+
+    #[test]
+    fn encodes_enabled_fields_only() {
+        let alarm = Alarm::new()
+            .minute(30)
+            .and_then(|alarm| alarm.hour(7))
+            .unwrap();
+
+        assert_eq!(alarm.to_registers(), [0x30, 0x07, 0x80]);
+        assert_eq!(Alarm::from_registers(alarm.to_registers(), false), alarm);
+    }
+
+    #[test]
+    fn weekdays_accumulate_and_date_replaces_them() {
+        let alarm = Alarm::new()
+            .weekday(Weekday::Monday)
+            .weekday(Weekday::Friday);
+        assert_eq!(alarm.get_day(), Some(AlarmDay::Weekdays(0b0010_0010)));
+        assert!(!alarm.is_date());
+
+        let alarm = alarm.date(31).unwrap();
+        assert_eq!(alarm.to_registers(), [0x80, 0x80, 0x31]);
+        assert_eq!(Alarm::from_registers(alarm.to_registers(), true), alarm);
+    }
+
+    #[test]
+    fn rejects_out_of_range_fields() {
+        assert!(Alarm::new().minute(60).is_none());
+        assert!(Alarm::new().minute(75).is_none());
+        assert!(Alarm::new().hour(24).is_none());
+        assert!(Alarm::new().date(0).is_none());
+        assert!(Alarm::new().date(40).is_none());
+        assert!(Alarm::new().weekdays(0).is_none());
+        assert!(Alarm::new().weekdays(0b1000_0001).is_none());
+
+        assert!(Alarm::new().minute(59).is_some());
+        assert!(Alarm::new().hour(23).is_some());
+        assert!(Alarm::new().weekdays(0b0111_1111).is_some());
+    }
+}
@@ -0,0 +1,208 @@
+//! Typed Extension, Flag, Control and Event Control registers
+
+use crate::rtc::{
+    clkout::ClockOutFrequency,
+    event::EventFilter,
+    registers::{
+        bits::{
+            AF, AIE, ECP, EHL, EIE, ERST, ET_MASK, ET_SHIFT, EVF, FD_MASK, FD_SHIFT, RESET,
+            TD_MASK, TE, TF, TIE, UF, UIE, USEL, V1F, V2F, WADA,
+        },
+        Register,
+    },
+    timer::TimerClock,
+};
+
+/// A register made up of bit fields, read and written as a whole.
+pub trait Bitfield: Copy {
+    /// Address of the register.
+    const REGISTER: Register;
+
+    /// Creates the register from its value.
+    fn from_bits(bits: u8) -> Self;
+
+    /// Value of the register.
+    fn bits(&self) -> u8;
+}
+
+/// Replaces the bits selected by `mask` with `value`, shifted into place.
+fn set_field(bits: u8, mask: u8, shift: u8, value: u8) -> u8 {
+    (bits & !mask) | ((value << shift) & mask)
+}
+
+/// Defines a register newtype, with a getter and setter for each single-bit field.
+macro_rules! bitfield {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $register:ident {
+            $($(#[$doc:meta])* $bit:ident: $getter:ident, $setter:ident;)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $name(u8);
+
+        impl $name {
+            #[doc = concat!("Creates a [`", stringify!($name), "`] from its value.")]
+            #[must_use]
+            pub fn new(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            /// Value of the register.
+            #[must_use]
+            pub fn bits(&self) -> u8 {
+                self.0
+            }
+
+            $(
+                $(#[$doc])*
+                #[must_use]
+                pub fn $getter(&self) -> bool {
+                    self.0 & (1 << $bit) != 0
+                }
+
+                #[doc = concat!("Set or clear the ", stringify!($bit), " bit.")]
+                pub fn $setter(&mut self, value: bool) {
+                    self.0 = (self.0 & !(1 << $bit)) | (u8::from(value) << $bit);
+                }
+            )*
+        }
+
+        impl Bitfield for $name {
+            const REGISTER: Register = Register::$register;
+
+            fn from_bits(bits: u8) -> Self {
+                Self(bits)
+            }
+
+            fn bits(&self) -> u8 {
+                self.0
+            }
+        }
+    };
+}
+
+bitfield!(
+    /// Extension register (0x1D).
+    ExtensionReg, Extension {
+        /// WADA: the alarm matches on the date, rather than the weekday.
+        WADA: wada, set_wada;
+        /// USEL: the periodic time update occurs once per minute, rather than once per second.
+        USEL: usel, set_usel;
+        /// TE: the countdown timer is enabled.
+        TE: te, set_te;
+    }
+);
+
+impl ExtensionReg {
+    /// FD: frequency of the CLKOUT pin.
+    #[must_use]
+    pub fn fd(&self) -> ClockOutFrequency {
+        ClockOutFrequency::from((self.0 & FD_MASK) >> FD_SHIFT)
+    }
+
+    /// Set the FD bits.
+    pub fn set_fd(&mut self, value: ClockOutFrequency) {
+        self.0 = set_field(self.0, FD_MASK, FD_SHIFT, value as u8);
+    }
+
+    /// TD: source clock of the countdown timer.
+    #[must_use]
+    pub fn td(&self) -> TimerClock {
+        TimerClock::from(self.0 & TD_MASK)
+    }
+
+    /// Set the TD bits.
+    pub fn set_td(&mut self, value: TimerClock) {
+        self.0 = set_field(self.0, TD_MASK, 0, value as u8);
+    }
+}
+
+bitfield!(
+    /// Flag register (0x1E).
+    ///
+    /// Flags are cleared by writing 0, while writing 1 leaves them unchanged; a read-modify-write
+    /// would therefore clear any flag raised in between.
+    FlagReg, Flag {
+        /// UF: a periodic time update occurred.
+        UF: uf, set_uf;
+        /// TF: the countdown timer fired.
+        TF: tf, set_tf;
+        /// AF: the alarm fired.
+        AF: af, set_af;
+        /// EVF: an external event was detected.
+        EVF: evf, set_evf;
+        /// V2F: data was lost to a voltage drop or power-on reset; the time is invalid until
+        /// set again.
+        V2F: v2f, set_v2f;
+        /// V1F: temperature compensation stopped due to a voltage drop.
+        V1F: v1f, set_v1f;
+    }
+);
+
+bitfield!(
+    /// Control register (0x1F).
+    ControlReg, Control {
+        /// UIE: the periodic time update interrupt is enabled.
+        UIE: uie, set_uie;
+        /// TIE: the countdown timer interrupt is enabled.
+        TIE: tie, set_tie;
+        /// AIE: the alarm interrupt is enabled.
+        AIE: aie, set_aie;
+        /// EIE: the external event interrupt is enabled.
+        EIE: eie, set_eie;
+        /// RESET: the divider chain is held in reset.
+        RESET: reset, set_reset;
+    }
+);
+
+bitfield!(
+    /// Event Control register (0x2F).
+    EventControlReg, Event {
+        /// ECP: a time stamp is captured on each event.
+        ECP: ecp, set_ecp;
+        /// EHL: a rising edge, or high level, is detected as an event.
+        EHL: ehl, set_ehl;
+        /// ERST: the time stamp is reset; cleared by the rtc.
+        ERST: erst, set_erst;
+    }
+);
+
+impl EventControlReg {
+    /// ET: debounce filter of the EVI pin.
+    #[must_use]
+    pub fn et(&self) -> EventFilter {
+        EventFilter::from((self.0 & ET_MASK) >> ET_SHIFT)
+    }
+
+    /// Set the ET bits.
+    pub fn set_et(&mut self, value: EventFilter) {
+        self.0 = set_field(self.0, ET_MASK, ET_SHIFT, value as u8);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ControlReg, ExtensionReg};
+    use crate::rtc::{clkout::ClockOutFrequency, timer::TimerClock};
+
+    #[test]
+    fn sets_fields_without_touching_neighbours() {
+        let mut extension = ExtensionReg::new(0b1111_1111);
+        extension.set_fd(ClockOutFrequency::Hz1024);
+        extension.set_td(TimerClock::Hz64);
+        extension.set_te(false);
+
+        assert_eq!(extension.bits(), 0b1110_0101);
+        assert_eq!(extension.fd(), ClockOutFrequency::Hz1024);
+        assert_eq!(extension.td(), TimerClock::Hz64);
+        assert!(extension.wada() && !extension.te());
+
+        let mut control = ControlReg::default();
+        control.set_reset(true);
+        control.set_aie(true);
+        assert_eq!(control.bits(), 0b0000_1001);
+    }
+}
@@ -0,0 +1,30 @@
+//! Clock Output
+
+/// Frequency of the CLKOUT pin, i.e. the FD bits of the Extension register.
+///
+/// The output itself is enabled by driving the CLKOE pin high.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ClockOutFrequency {
+    /// 32.768 kHz
+    #[default]
+    Hz32768 = 0b00,
+    /// 1024 Hz
+    Hz1024 = 0b01,
+    /// 1 Hz
+    Hz1 = 0b10,
+}
+
+impl ClockOutFrequency {
+    /// Get variant from the FD bits.
+    #[must_use]
+    pub fn from(val: u8) -> Self {
+        match val & 0b11 {
+            0b01 => Self::Hz1024,
+            0b10 => Self::Hz1,
+            // Both 0b00 and 0b11 select 32.768 kHz.
+            _ => Self::Hz32768,
+        }
+    }
+}
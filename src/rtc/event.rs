@@ -0,0 +1,157 @@
+//! External Event Input
+
+use crate::rtc::bitfields::EventControlReg;
+
+/// Edge on the EVI pin that is detected as an event, i.e. the EHL bit.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EventEdge {
+    /// Falling edge, or low level.
+    #[default]
+    Falling,
+    /// Rising edge, or high level.
+    Rising,
+}
+
+/// Debounce filter on the EVI pin, i.e. the ET bits.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EventFilter {
+    /// No filtering.
+    #[default]
+    None = 0b00,
+    /// 3.9 ms sampling period.
+    Ms3_9 = 0b01,
+    /// 15.6 ms sampling period.
+    Ms15_6 = 0b10,
+    /// 125 ms sampling period.
+    Ms125 = 0b11,
+}
+
+impl EventFilter {
+    /// Get variant from the ET bits.
+    #[must_use]
+    pub fn from(val: u8) -> Self {
+        match val & 0b11 {
+            0b00 => Self::None,
+            0b01 => Self::Ms3_9,
+            0b10 => Self::Ms15_6,
+            _ => Self::Ms125,
+        }
+    }
+}
+
+/// External event input configuration, i.e. the Event Control register.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EventInput {
+    edge: EventEdge,
+    filter: EventFilter,
+    capture: bool,
+}
+
+impl Default for EventInput {
+    fn default() -> Self {
+        Self {
+            edge: EventEdge::default(),
+            filter: EventFilter::default(),
+            capture: true,
+        }
+    }
+}
+
+impl EventInput {
+    /// Creates an [`EventInput`], detecting falling edges without filtering, and capturing a
+    /// time stamp on each event.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the edge detected as an event.
+    #[must_use]
+    pub fn edge(mut self, value: EventEdge) -> Self {
+        self.edge = value;
+        self
+    }
+
+    /// Set the debounce filter.
+    #[must_use]
+    pub fn filter(mut self, value: EventFilter) -> Self {
+        self.filter = value;
+        self
+    }
+
+    /// Capture a time stamp on each event; without it, there is no time stamp to read.
+    #[must_use]
+    pub fn capture(mut self, value: bool) -> Self {
+        self.capture = value;
+        self
+    }
+
+    /// Edge detected as an event.
+    #[must_use]
+    pub fn get_edge(&self) -> EventEdge {
+        self.edge
+    }
+
+    /// Debounce filter.
+    #[must_use]
+    pub fn get_filter(&self) -> EventFilter {
+        self.filter
+    }
+
+    /// Whether a time stamp is captured on each event.
+    #[must_use]
+    pub fn get_capture(&self) -> bool {
+        self.capture
+    }
+
+    /// Encodes the Event Control register.
+    pub(crate) fn to_register(self) -> EventControlReg {
+        let mut value = EventControlReg::default();
+        value.set_ecp(self.capture);
+        value.set_ehl(self.edge == EventEdge::Rising);
+        value.set_et(self.filter);
+
+        value
+    }
+
+    /// Decodes the Event Control register.
+    pub(crate) fn from_register(value: EventControlReg) -> Self {
+        Self {
+            edge: if value.ehl() {
+                EventEdge::Rising
+            } else {
+                EventEdge::Falling
+            },
+            filter: value.et(),
+            capture: value.ecp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventEdge, EventFilter, EventInput};
+
+    #[test]
+    fn encodes_event_control() {
+        let config = EventInput::new()
+            .edge(EventEdge::Rising)
+            .filter(EventFilter::Ms15_6)
+            .capture(true);
+
+        assert_eq!(config.to_register().bits(), 0b1110_0000);
+        assert_eq!(EventInput::from_register(config.to_register()), config);
+    }
+
+    #[test]
+    fn captures_time_stamps_by_default() {
+        assert!(EventInput::new().get_capture());
+        assert_eq!(EventInput::new().to_register().bits(), 0b1000_0000);
+    }
+}
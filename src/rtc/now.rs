@@ -2,57 +2,202 @@ use crate::error::DriverError;
 use crate::models::ClockData;
 use core::fmt::Debug;
 use embedded_hal::i2c::{I2c, SevenBitAddress};
+use embedded_hal_async::i2c::I2c as AsyncI2c;
 
-/// Trait to read from I2C periph
-pub trait Readable: Debug + Copy + Clone {
-    /// Fetch the latest date and time.
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`DriverError`]
-    fn now<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        addr: u8,
-        data: &mut ClockData,
-    ) -> Result<(), DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>;
+use super::registers::Register;
+
+/// Number of time registers, Hundredths (0x10) through Year (0x17).
+pub(crate) const TIME_REGISTERS_LEN: usize = 8;
+
+/// Upper bound on burst reads before giving up with [`DriverError::Timeout`].
+const MAX_READ_ATTEMPTS: usize = 3;
+
+/// Defines [`Readable`] and [`ReadableAsync`] from a single definition, as per `impl_driver!`.
+macro_rules! readable {
+    (
+        $(#[$attr:meta])*
+        $readable:ident, $i2c:ident, $registers:ident, [$($maybe_await:tt)*] $(, $async:tt)?
+    ) => {
+        $(#[$attr])*
+        pub trait $readable: Debug + Copy + Clone {
+            /// Fetch the latest date and time.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`], [`DriverError::Timeout`] if no consistent reading is
+            /// obtained.
+            $($async)? fn now<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                addr: u8,
+                data: &mut ClockData,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>;
+        }
+
+        impl $readable for ClockData {
+            /// Reads Hundredths through Year in a single auto-incrementing transaction.
+            ///
+            /// Hundredths and Seconds are then read back; should Seconds have changed, or
+            /// Hundredths wrapped, a rollover may have happened mid-read and the whole block is
+            /// read again, a bounded number of times.
+            $($async)? fn now<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                addr: u8,
+                data: &mut ClockData,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                let mut cregs = super::registers::$registers(addr);
+                let mut block = [0; TIME_REGISTERS_LEN];
+
+                for _ in 0..MAX_READ_ATTEMPTS {
+                    cregs
+                        .read_registers(i2c, Register::Hundredths, &mut block)
+                        $($maybe_await)*?;
+
+                    let mut check = [0; 2];
+                    cregs
+                        .read_registers(i2c, Register::Hundredths, &mut check)
+                        $($maybe_await)*?;
+
+                    let (hundredths, seconds) = (check[0], check[1]);
+                    if seconds == block[1] && hundredths >= block[0] {
+                        *data = ClockData::from_registers(&block);
+
+                        return Ok(());
+                    }
+                }
+
+                Err(DriverError::Timeout)
+            }
+        }
+    };
 }
 
-impl Readable for ClockData {
-    fn now<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        addr: u8,
-        data: &mut ClockData,
-    ) -> Result<(), DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        use crate::models::misc::bcd_to_dec;
-
-        let mut cregs = super::registers::new(addr);
-
-        let latest = ClockData {
-            hundredths: bcd_to_dec(
-                cregs.read_register(i2c, super::registers::Register::Hundredths)?,
+readable!(
+    /// Trait to read from I2C periph
+    Readable, I2c, new, []
+);
+
+readable!(
+    /// Async counterpart of [`Readable`].
+    #[allow(async_fn_in_trait)]
+    ReadableAsync, AsyncI2c, new_async, [.await], async
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Readable;
+    use crate::{error::DriverError, models::ClockData};
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+
+    const ADDR: u8 = 0x32;
+
+    #[test]
+    fn reads_time_block_in_one_transaction() {
+        let block = vec![0x42, 0x59, 0x59, 0x23, 0x02, 0x07, 0x10, 0x24];
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x10], block),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x43, 0x59]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let mut data = ClockData::new();
+        ClockData::new().now(&mut i2c, ADDR, &mut data).unwrap();
+
+        assert_eq!(data.hundredths(), 42);
+        assert_eq!(data.seconds(), 59);
+        assert_eq!(data.minutes(), 59);
+        assert_eq!(data.hours(), 23);
+        assert_eq!(data.weekday(), 0x02);
+        assert_eq!(data.date(), 7);
+        assert_eq!(data.month(), 10);
+        assert_eq!(data.year(), 24);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn rereads_after_rollover() {
+        let expectations = [
+            Transaction::write_read(
+                ADDR,
+                vec![0x10],
+                vec![0x99, 0x59, 0x59, 0x23, 0x02, 0x07, 0x10, 0x24],
+            ),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x00]),
+            Transaction::write_read(
+                ADDR,
+                vec![0x10],
+                vec![0x01, 0x00, 0x00, 0x00, 0x04, 0x08, 0x10, 0x24],
+            ),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x01, 0x00]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let mut data = ClockData::new();
+        ClockData::new().now(&mut i2c, ADDR, &mut data).unwrap();
+
+        assert_eq!(data.hours(), 0);
+        assert_eq!(data.date(), 8);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn times_out_without_a_consistent_reading() {
+        let block = vec![0x99, 0x59, 0x59, 0x23, 0x02, 0x07, 0x10, 0x24];
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x10], block.clone()),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x00]),
+            Transaction::write_read(ADDR, vec![0x10], block.clone()),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x00]),
+            Transaction::write_read(ADDR, vec![0x10], block),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x00, 0x00]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let mut data = ClockData::new();
+        let result = ClockData::new().now(&mut i2c, ADDR, &mut data);
+
+        assert!(matches!(result, Err(DriverError::Timeout)));
+
+        i2c.done();
+    }
+
+    #[test]
+    fn reads_time_block_async() {
+        use super::ReadableAsync;
+
+        let expectations = [
+            Transaction::write_read(
+                ADDR,
+                vec![0x10],
+                vec![0x42, 0x30, 0x15, 0x12, 0x02, 0x07, 0x10, 0x24],
             ),
-            seconds: bcd_to_dec(cregs.read_register(i2c, super::registers::Register::Seconds)?),
-            minutes: bcd_to_dec(cregs.read_register(i2c, super::registers::Register::Minutes)?),
-            hours: bcd_to_dec(cregs.read_register(i2c, super::registers::Register::Hours)?),
-            date: bcd_to_dec(cregs.read_register(i2c, super::registers::Register::Date)?),
-            month: bcd_to_dec(cregs.read_register(i2c, super::registers::Register::Month)?),
-            year: bcd_to_dec(cregs.read_register(i2c, super::registers::Register::Year)?),
+            Transaction::write_read(ADDR, vec![0x10], vec![0x43, 0x30]),
+        ];
+        let mut i2c = Mock::new(&expectations);
 
-            // Read directly as a byte.
-            weekday: cregs.read_register(i2c, super::registers::Register::Weekday)?,
-        };
+        let mut data = ClockData::new();
+        embassy_futures::block_on(ReadableAsync::now(
+            &mut ClockData::new(),
+            &mut i2c,
+            ADDR,
+            &mut data,
+        ))
+        .unwrap();
 
-        *data = latest;
+        assert_eq!(data.seconds(), 30);
+        assert_eq!(data.minutes(), 15);
+        assert_eq!(data.hours(), 12);
 
-        Ok(())
+        i2c.done();
     }
 }
@@ -0,0 +1,70 @@
+//! Frequency Offset
+
+/// Resolution of the Offset register, in ppm.
+pub const OFFSET_STEP_PPM: f32 = 0.2384;
+
+/// Smallest offset, in ppm.
+pub const MIN_OFFSET_PPM: f32 = MIN_STEPS as f32 * OFFSET_STEP_PPM;
+
+/// Largest offset, in ppm.
+pub const MAX_OFFSET_PPM: f32 = MAX_STEPS as f32 * OFFSET_STEP_PPM;
+
+/// The Offset register holds a 6-bit two's complement value.
+const MIN_STEPS: i8 = -32;
+const MAX_STEPS: i8 = 31;
+const OFFSET_MASK: u8 = 0b0011_1111;
+
+/// Encodes the Offset register, rounding to the nearest step. Returns `None` if the offset is
+/// out of range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn ppm_to_register(ppm: f32) -> Option<u8> {
+    if !ppm.is_finite() {
+        return None;
+    }
+
+    // `f32::round` is unavailable in `core`; casts truncate towards zero.
+    let steps = ppm / OFFSET_STEP_PPM;
+    let steps = if steps < 0.0 {
+        steps - 0.5
+    } else {
+        steps + 0.5
+    } as i32;
+
+    (i32::from(MIN_STEPS)..=i32::from(MAX_STEPS))
+        .contains(&steps)
+        .then_some(steps as u8 & OFFSET_MASK)
+}
+
+/// Decodes the Offset register.
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn register_to_ppm(value: u8) -> f32 {
+    // Sign-extend from 6 bits.
+    let steps = ((value & OFFSET_MASK) << 2) as i8 >> 2;
+
+    f32::from(steps) * OFFSET_STEP_PPM
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ppm_to_register, register_to_ppm, MAX_OFFSET_PPM, MIN_OFFSET_PPM};
+
+    #[test]
+    fn converts_twos_complement() {
+        assert_eq!(ppm_to_register(0.0), Some(0));
+        assert_eq!(ppm_to_register(0.2384), Some(0b00_0001));
+        assert_eq!(ppm_to_register(-0.2384), Some(0b11_1111));
+        assert_eq!(ppm_to_register(MAX_OFFSET_PPM), Some(0b01_1111));
+        assert_eq!(ppm_to_register(MIN_OFFSET_PPM), Some(0b10_0000));
+
+        assert_eq!(register_to_ppm(0b11_1111), -0.2384);
+        assert_eq!(register_to_ppm(0b10_0000), MIN_OFFSET_PPM);
+        assert_eq!(register_to_ppm(0b01_1111), MAX_OFFSET_PPM);
+    }
+
+    #[test]
+    fn rejects_out_of_range() {
+        assert_eq!(ppm_to_register(7.6), None);
+        assert_eq!(ppm_to_register(-7.8), None);
+        assert_eq!(ppm_to_register(f32::NAN), None);
+    }
+}
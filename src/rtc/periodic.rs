@@ -0,0 +1,20 @@
+//! Periodic Time Update
+
+/// Period of the time update interrupt, i.e. the USEL bit of the Extension register.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UpdatePeriod {
+    /// Once per second.
+    #[default]
+    Second,
+    /// Once per minute.
+    Minute,
+}
+
+impl UpdatePeriod {
+    /// Value of the USEL bit.
+    pub(crate) fn usel(self) -> bool {
+        matches!(self, Self::Minute)
+    }
+}
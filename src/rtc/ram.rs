@@ -0,0 +1,87 @@
+//! User RAM
+
+/// A value packed into the single byte of user RAM.
+pub trait RamValue: Sized {
+    /// Packs the value into a byte.
+    fn to_byte(&self) -> u8;
+
+    /// Unpacks the value from a byte.
+    fn from_byte(byte: u8) -> Self;
+}
+
+impl RamValue for u8 {
+    fn to_byte(&self) -> u8 {
+        *self
+    }
+
+    fn from_byte(byte: u8) -> Self {
+        byte
+    }
+}
+
+const PAYLOAD_MASK: u8 = 0x0F;
+
+/// Packs a 4-bit payload into the lower nibble, with its complement as the checksum in the
+/// upper nibble. Returns `None` if the payload does not fit in 4 bits.
+///
+/// The complement ensures neither a cleared (0x00) nor a floating (0xFF) byte passes the check.
+pub(crate) fn pack_checked(payload: u8) -> Option<u8> {
+    (payload <= PAYLOAD_MASK).then_some(((!payload & PAYLOAD_MASK) << 4) | payload)
+}
+
+/// Unpacks the 4-bit payload, returning `None` if the checksum does not match.
+pub(crate) fn unpack_checked(byte: u8) -> Option<u8> {
+    let payload = byte & PAYLOAD_MASK;
+
+    (byte >> 4 == !payload & PAYLOAD_MASK).then_some(payload)
+}
+
+/// Earliest century that can be kept in user RAM.
+const FIRST_CENTURY: u16 = 1900;
+
+/// Encodes the century as a 4-bit payload, counted in hundreds of years from 1900. Returns
+/// `None` unless the century is within 1900 to 3400.
+pub(crate) fn century_to_payload(century: u16) -> Option<u8> {
+    let payload = century.checked_sub(FIRST_CENTURY)? / 100;
+
+    u8::try_from(payload).ok().filter(|p| *p <= PAYLOAD_MASK)
+}
+
+/// Decodes the century from a 4-bit payload.
+pub(crate) fn century_from_payload(payload: u8) -> u16 {
+    FIRST_CENTURY + u16::from(payload & PAYLOAD_MASK) * 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{century_from_payload, century_to_payload, pack_checked, unpack_checked};
+
+    #[test]
+    fn round_trips_checked_nibble() {
+        for payload in 0..=0x0F {
+            let byte = pack_checked(payload).unwrap();
+            assert_eq!(unpack_checked(byte), Some(payload));
+        }
+
+        assert_eq!(pack_checked(0x10), None);
+    }
+
+    #[test]
+    fn detects_corruption() {
+        assert_eq!(unpack_checked(0x00), None);
+        assert_eq!(unpack_checked(0xFF), None);
+        assert_eq!(unpack_checked(pack_checked(0x05).unwrap() ^ 0x01), None);
+    }
+
+    #[test]
+    fn encodes_century_as_payload() {
+        assert_eq!(century_to_payload(2000), Some(1));
+        assert_eq!(century_from_payload(1), 2000);
+        assert_eq!(
+            century_from_payload(century_to_payload(3400).unwrap()),
+            3400
+        );
+        assert_eq!(century_to_payload(1800), None);
+        assert_eq!(century_to_payload(3500), None);
+    }
+}
@@ -1,9 +1,14 @@
 use crate::error::DriverError;
+use crate::rtc::bitfields::{Bitfield, FlagReg};
+use crate::trace::trace;
 use core::fmt::Debug;
-use embedded_hal::i2c::{I2c, SevenBitAddress};
+use embedded_hal::i2c::{I2c, Operation, SevenBitAddress};
+use embedded_hal_async::i2c::I2c as AsyncI2c;
 
 /// Mapping of all the registers used to operate the RTC module
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
 #[allow(clippy::doc_markdown)]
 pub enum Register {
     /// RAM
@@ -12,6 +17,12 @@ pub enum Register {
     MinutesAlarm = 0x08,
     /// HoursAlarm
     HoursAlarm = 0x09,
+    /// Weekday/Date Alarm
+    WeekdayDateAlarm = 0x0A,
+    /// Timer Counter 0, lower 8 bits of the countdown timer
+    TimerCounter0 = 0x0B,
+    /// Timer Counter 1, upper 4 bits of the countdown timer
+    TimerCounter1 = 0x0C,
     /// Hundredths
     Hundredths = 0x10,
     /// Seconds
@@ -28,14 +39,16 @@ pub enum Register {
     Month = 0x16,
     /// Year
     Year = 0x17,
-    /// ControlReset
-    ControlReset = 0,
     /// Extension Register
     Extension = 0x1D,
     /// Flag Register
     Flag = 0x1E,
     /// Control Register
     Control = 0x1F,
+    /// Hundredths Capture, the time stamp of the last event
+    HundredthsCapture = 0x20,
+    /// Seconds Capture, the time stamp of the last event
+    SecondsCapture = 0x21,
     /// Offset
     Offset = 0x2C,
     /// Event Control
@@ -47,103 +60,374 @@ impl Register {
     pub fn address(self) -> u8 {
         self as u8
     }
+
+    /// Bits the rtc may change by itself, so are not compared when verifying a write.
+    fn volatile_bits(self) -> u8 {
+        match self {
+            Self::Flag => FLAG_MASK,
+            Self::Event => 1 << bits::ERST,
+            _ => 0,
+        }
+    }
+}
+
+/// Implemented bits of the Flag register.
+const FLAG_MASK: u8 = 0b0011_1111;
+
+/// Bit positions within the Extension, Flag, Control and Event Control registers.
+pub mod bits {
+    /// Extension: Weekday Alarm / Date Alarm selection.
+    pub const WADA: u8 = 6;
+    /// Extension: Update Interrupt Select, once per second or once per minute.
+    pub const USEL: u8 = 5;
+    /// Extension: Timer Enable.
+    pub const TE: u8 = 4;
+    /// Extension: Offset of the CLKOUT Frequency selection.
+    pub const FD_SHIFT: u8 = 2;
+    /// Extension: CLKOUT Frequency selection, 2 bits wide.
+    pub const FD_MASK: u8 = 0b0000_1100;
+    /// Extension: Timer Clock Frequency selection, 2 bits wide.
+    pub const TD_MASK: u8 = 0b0000_0011;
+
+    /// Flag: Update Flag.
+    pub const UF: u8 = 5;
+    /// Flag: Timer Flag.
+    pub const TF: u8 = 4;
+    /// Flag: Alarm Flag.
+    pub const AF: u8 = 3;
+    /// Flag: Event Flag.
+    pub const EVF: u8 = 2;
+    /// Flag: Voltage Low Flag 2, data loss.
+    pub const V2F: u8 = 1;
+    /// Flag: Voltage Low Flag 1, temperature compensation stopped.
+    pub const V1F: u8 = 0;
+
+    /// Control: Update Interrupt Enable.
+    pub const UIE: u8 = 5;
+    /// Control: Timer Interrupt Enable.
+    pub const TIE: u8 = 4;
+    /// Control: Alarm Interrupt Enable.
+    pub const AIE: u8 = 3;
+    /// Control: Event Interrupt Enable.
+    pub const EIE: u8 = 2;
+    /// Control: Reset, holding the divider chain in reset.
+    pub const RESET: u8 = 0;
+
+    /// Event Control: Event Capture enable, time stamping each event.
+    pub const ECP: u8 = 7;
+    /// Event Control: Event High/Low, the edge detected as an event.
+    pub const EHL: u8 = 6;
+    /// Event Control: Offset of the Event Filtering Time.
+    pub const ET_SHIFT: u8 = 4;
+    /// Event Control: Event Filtering Time, 2 bits wide.
+    pub const ET_MASK: u8 = 0b0011_0000;
+    /// Event Control: Event Reset, clearing the time stamp.
+    pub const ERST: u8 = 0;
 }
 
 #[derive(Debug, Copy, Clone, Default)]
 #[allow(clippy::module_name_repetitions)]
 pub struct ClockRegisters {
     device_address: u8,
+    verify: bool,
 }
 
 pub fn new(address: u8) -> ClockRegisters {
     ClockRegisters {
         device_address: address,
+        verify: false,
     }
 }
 
-impl ClockRegisters {
-    /// Write a single bit to the specified register
-    pub fn write_bit<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        reg_addr: u8,
-        bit_addr: u8,
-        bit_to_write: bool,
-    ) -> Result<bool, DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        let mut value = 0;
-
-        if let Ok(reg_value) = self.read_register_by_addr(i2c, reg_addr) {
-            value = reg_value;
+/// Async counterpart of [`ClockRegisters`].
+#[derive(Debug, Copy, Clone, Default)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ClockRegistersAsync {
+    device_address: u8,
+    verify: bool,
+}
+
+pub fn new_async(address: u8) -> ClockRegistersAsync {
+    ClockRegistersAsync {
+        device_address: address,
+        verify: false,
+    }
+}
+
+/// Implements the register access of [`ClockRegisters`] and [`ClockRegistersAsync`] from a
+/// single definition, as per `impl_driver!`.
+macro_rules! impl_clock_registers {
+    ($registers:ident, $i2c:ident, [$($maybe_await:tt)*] $(, $async:tt)?) => {
+        impl $registers {
+            /// When enabled, [`Self::modify`] reads back the value written.
+            #[must_use]
+            pub fn with_verify(mut self, verify: bool) -> Self {
+                self.verify = verify;
+                self
+            }
+
+            /// Read-modify-write the specified register, returning the value written.
+            ///
+            /// Nothing is written should the read fail. When verifying, the register is then
+            /// read back, and [`DriverError::VerifyFailed`] returned should it differ from the
+            /// value written, other than in bits the rtc may change by itself.
+            pub $($async)? fn modify<I2C, F>(
+                &mut self,
+                i2c: &mut I2C,
+                register: Register,
+                modify: F,
+            ) -> Result<u8, DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+                F: FnOnce(u8) -> u8,
+            {
+                let written = modify(self.read_register(i2c, register)$($maybe_await)*?);
+                self.write_register(i2c, register, written)$($maybe_await)*?;
+
+                if self.verify {
+                    let read = self.read_register(i2c, register)$($maybe_await)*?;
+                    let mask = !register.volatile_bits();
+
+                    if read & mask != written & mask {
+                        return Err(DriverError::VerifyFailed {
+                            register,
+                            written,
+                            read,
+                        });
+                    }
+                }
+
+                Ok(written)
+            }
+
+            /// Clear the flags that `clear` sets to 0 in the Flag register.
+            ///
+            /// Flags are cleared by writing 0, while writing 1 leaves them unchanged; the other
+            /// flags are therefore written as 1 rather than read back, so that none raised in
+            /// between are lost.
+            pub $($async)? fn clear_flag<I2C, F>(
+                &mut self,
+                i2c: &mut I2C,
+                clear: F,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+                F: FnOnce(&mut FlagReg),
+            {
+                let mut flags = FlagReg::new(FLAG_MASK);
+                clear(&mut flags);
+
+                self.write_bitfield(i2c, flags)$($maybe_await)*
+            }
+
+            /// Read a register made up of bit fields.
+            pub $($async)? fn read_bitfield<I2C, R>(
+                &mut self,
+                i2c: &mut I2C,
+            ) -> Result<R, DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+                R: Bitfield,
+            {
+                let value = self.read_register(i2c, R::REGISTER)$($maybe_await)*?;
+
+                Ok(R::from_bits(value))
+            }
+
+            /// Write a register made up of bit fields.
+            pub $($async)? fn write_bitfield<I2C, R>(
+                &mut self,
+                i2c: &mut I2C,
+                value: R,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+                R: Bitfield,
+            {
+                self.write_register(i2c, R::REGISTER, value.bits())$($maybe_await)*
+            }
+
+            /// Read-modify-write a register made up of bit fields, returning the value written.
+            pub $($async)? fn modify_bitfield<I2C, R, F>(
+                &mut self,
+                i2c: &mut I2C,
+                modify: F,
+            ) -> Result<R, DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+                R: Bitfield,
+                F: FnOnce(&mut R),
+            {
+                let written = self
+                    .modify(i2c, R::REGISTER, |bits| {
+                        let mut value = R::from_bits(bits);
+                        modify(&mut value);
+                        value.bits()
+                    })
+                    $($maybe_await)*?;
+
+                Ok(R::from_bits(written))
+            }
+
+            pub $($async)? fn read_register<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                register: Register,
+            ) -> Result<u8, DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                let mut data = [0];
+                i2c.write_read(self.device_address, &[register.address()], &mut data)
+                    $($maybe_await)*?;
+                trace!("read {:#x} from {:?}", data[0], register);
+
+                Ok(u8::from_le_bytes(data))
+            }
+
+            /// Read consecutive registers, starting at `register`, in a single transaction.
+            ///
+            /// The rtc auto-increments the register address, so `buf.len()` registers are read.
+            pub $($async)? fn read_registers<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                register: Register,
+                buf: &mut [u8],
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                i2c.write_read(self.device_address, &[register.address()], buf)$($maybe_await)*?;
+                trace!("read {:?} from {:?} onwards", buf, register);
+
+                Ok(())
+            }
+
+            pub $($async)? fn write_register<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                register: Register,
+                byte: u8,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                i2c.write(self.device_address, &[register.address(), byte])$($maybe_await)*?;
+                trace!("wrote {:#x} to {:?}", byte, register);
+                Ok(())
+            }
+
+            /// Write consecutive registers, starting at `register`, in a single transaction.
+            pub $($async)? fn write_registers<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                register: Register,
+                bytes: &[u8],
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                // Adjacent write operations are sent back-to-back, without a repeated start.
+                i2c.transaction(
+                    self.device_address,
+                    &mut [
+                        Operation::Write(&[register.address()]),
+                        Operation::Write(bytes),
+                    ],
+                )$($maybe_await)*?;
+                trace!("wrote {:?} to {:?} onwards", bytes, register);
+
+                Ok(())
+            }
+
+            pub $($async)? fn write_register_by_addr<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                reg_addr: u8,
+                byte: u8,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                i2c.write(self.device_address, &[reg_addr, byte])$($maybe_await)*?;
+                trace!("wrote {:#x} to {:#x}", byte, reg_addr);
+
+                Ok(())
+            }
+
+            pub $($async)? fn read_register_by_addr<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                reg_addr: u8,
+            ) -> Result<u8, DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                let mut data = [0];
+                i2c.write_read(self.device_address, &[reg_addr], &mut data)$($maybe_await)*?;
+                trace!("read {:#x} from {:#x}", data[0], reg_addr);
+                Ok(u8::from_le_bytes(data))
+            }
         }
+    };
+}
 
-        value &= !(1 << bit_addr);
-        value |= u8::from(bit_to_write) << bit_addr;
+impl_clock_registers!(ClockRegisters, I2c, []);
+impl_clock_registers!(ClockRegistersAsync, AsyncI2c, [.await], async);
 
-        self.write_register_by_addr(i2c, reg_addr, value)?;
+#[cfg(test)]
+mod tests {
+    use super::{new, Register};
+    use crate::error::DriverError;
+    use embedded_hal::i2c::ErrorKind;
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
 
-        Ok(true)
-    }
+    const ADDR: u8 = 0x32;
 
-    pub fn read_register<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        register: Register,
-    ) -> Result<u8, DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        let mut data = [0];
-        i2c.write_read(self.device_address, &[register.address()], &mut data)?;
-        // debug!("data: {:b}", data);
-
-        Ok(u8::from_le_bytes(data))
-    }
+    #[test]
+    fn modify_writes_nothing_after_a_failed_read() {
+        let expectations =
+            [Transaction::write_read(ADDR, vec![0x1F], vec![0x00]).with_error(ErrorKind::Other)];
+        let mut i2c = Mock::new(&expectations);
 
-    pub fn write_register<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        register: Register,
-        byte: u8,
-    ) -> Result<(), DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        i2c.write(self.device_address, &[register.address(), byte])?;
-        Ok(())
-    }
+        let result = new(ADDR).modify(&mut i2c, Register::Control, |bits| bits | 0b0000_1000);
+        assert!(matches!(result, Err(DriverError::I2c(ErrorKind::Other))));
 
-    pub fn write_register_by_addr<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        reg_addr: u8,
-        byte: u8,
-    ) -> Result<(), DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        i2c.write(self.device_address, &[reg_addr, byte])?;
-
-        Ok(())
+        i2c.done();
     }
 
-    pub fn read_register_by_addr<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        reg_addr: u8,
-    ) -> Result<u8, DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        let mut data = [0];
-        i2c.write_read(self.device_address, &[reg_addr], &mut data)?;
-        Ok(u8::from_le_bytes(data))
+    #[test]
+    fn modify_reports_a_failed_verify() {
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0011_0000]),
+            Transaction::write(ADDR, vec![0x1F, 0b0011_1000]),
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0011_0000]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let result = new(ADDR)
+            .with_verify(true)
+            .modify(&mut i2c, Register::Control, |bits| bits | 0b0000_1000);
+        assert!(matches!(
+            result,
+            Err(DriverError::VerifyFailed {
+                register: Register::Control,
+                written: 0b0011_1000,
+                read: 0b0011_0000,
+            })
+        ));
+
+        i2c.done();
     }
 }
@@ -0,0 +1,184 @@
+//! Countdown Timer
+
+use core::time::Duration;
+
+/// Largest value of the 12-bit countdown timer.
+pub const MAX_TICKS: u16 = 0x0FFF;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Source clock of the countdown timer, i.e. the TD bits of the Extension register.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TimerClock {
+    /// 4096 Hz
+    #[default]
+    Hz4096 = 0b00,
+    /// 64 Hz
+    Hz64 = 0b01,
+    /// 1 Hz
+    Hz1 = 0b10,
+    /// 1/60 Hz, i.e. once a minute.
+    PerMinute = 0b11,
+}
+
+impl TimerClock {
+    /// Every source clock, finest first.
+    const ALL: [TimerClock; 4] = [Self::Hz4096, Self::Hz64, Self::Hz1, Self::PerMinute];
+
+    /// Get variant from the TD bits.
+    #[must_use]
+    pub fn from(val: u8) -> Self {
+        match val & 0b11 {
+            0b00 => Self::Hz4096,
+            0b01 => Self::Hz64,
+            0b10 => Self::Hz1,
+            _ => Self::PerMinute,
+        }
+    }
+
+    /// Frequency as a fraction, `(numerator, denominator)` in Hz.
+    fn frequency(self) -> (u128, u128) {
+        match self {
+            Self::Hz4096 => (4096, 1),
+            Self::Hz64 => (64, 1),
+            Self::Hz1 => (1, 1),
+            Self::PerMinute => (1, 60),
+        }
+    }
+}
+
+/// Countdown timer configuration.
+///
+/// The timer counts down from `ticks` at the rate of its [`TimerClock`], raising the timer
+/// flag when it reaches zero, then reloads and repeats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CountdownTimerFields"))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CountdownTimer {
+    clock: TimerClock,
+    ticks: u16,
+}
+
+impl CountdownTimer {
+    /// Creates a [`CountdownTimer`]. Returns `None` unless `ticks` is within `1..=4095`.
+    #[must_use]
+    pub fn new(clock: TimerClock, ticks: u16) -> Option<Self> {
+        (1..=MAX_TICKS)
+            .contains(&ticks)
+            .then_some(Self { clock, ticks })
+    }
+
+    /// Creates a [`CountdownTimer`] for the period, using the finest source clock able to
+    /// count it. The period is rounded to the nearest tick.
+    ///
+    /// Returns `None` if the period is shorter than one tick at 4096 Hz, or longer than
+    /// 4095 minutes.
+    #[must_use]
+    pub fn from_duration(period: Duration) -> Option<Self> {
+        let nanos = period.as_nanos();
+
+        TimerClock::ALL.into_iter().find_map(|clock| {
+            let (num, den) = clock.frequency();
+            let divisor = den * NANOS_PER_SEC;
+            let ticks = (nanos * num + divisor / 2) / divisor;
+
+            u16::try_from(ticks)
+                .ok()
+                .and_then(|ticks| Self::new(clock, ticks))
+        })
+    }
+
+    /// Source clock.
+    #[must_use]
+    pub fn clock(&self) -> TimerClock {
+        self.clock
+    }
+
+    /// Ticks counted down per period.
+    #[must_use]
+    pub fn ticks(&self) -> u16 {
+        self.ticks
+    }
+
+    /// Period between timer events.
+    #[must_use]
+    pub fn period(&self) -> Duration {
+        let (num, den) = self.clock.frequency();
+        let nanos = u128::from(self.ticks) * den * NANOS_PER_SEC / num;
+
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+
+    /// Encodes the Timer Counter 0 and 1 registers.
+    pub(crate) fn to_registers(self) -> [u8; 2] {
+        self.ticks.to_le_bytes()
+    }
+}
+
+/// Decodes the Timer Counter 0 and 1 registers.
+pub(crate) fn ticks_from_registers(regs: [u8; 2]) -> u16 {
+    u16::from_le_bytes(regs) & MAX_TICKS
+}
+
+/// Fields of a deserialised [`CountdownTimer`], checked by [`CountdownTimer::new`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CountdownTimerFields {
+    clock: TimerClock,
+    ticks: u16,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CountdownTimerFields> for CountdownTimer {
+    type Error = &'static str;
+
+    fn try_from(fields: CountdownTimerFields) -> Result<Self, Self::Error> {
+        Self::new(fields.clock, fields.ticks).ok_or("ticks not within 1..=4095")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ticks_from_registers, CountdownTimer, TimerClock};
+    use core::time::Duration;
+
+    #[test]
+    fn rejects_out_of_range_ticks() {
+        assert!(CountdownTimer::new(TimerClock::Hz1, 0).is_none());
+        assert!(CountdownTimer::new(TimerClock::Hz1, 4096).is_none());
+        assert!(CountdownTimer::new(TimerClock::Hz1, 4095).is_some());
+    }
+
+    #[test]
+    fn picks_finest_clock_for_duration() {
+        let timer = CountdownTimer::from_duration(Duration::from_millis(500)).unwrap();
+        assert_eq!(timer.clock(), TimerClock::Hz4096);
+        assert_eq!(timer.ticks(), 2048);
+        assert_eq!(timer.period(), Duration::from_millis(500));
+
+        let timer = CountdownTimer::from_duration(Duration::from_secs(10)).unwrap();
+        assert_eq!(timer.clock(), TimerClock::Hz64);
+        assert_eq!(timer.ticks(), 640);
+
+        let timer = CountdownTimer::from_duration(Duration::from_secs(3600)).unwrap();
+        assert_eq!(timer.clock(), TimerClock::Hz1);
+
+        let timer = CountdownTimer::from_duration(Duration::from_secs(86_400)).unwrap();
+        assert_eq!(timer.clock(), TimerClock::PerMinute);
+        assert_eq!(timer.ticks(), 1440);
+
+        assert!(CountdownTimer::from_duration(Duration::from_secs(4096 * 60)).is_none());
+        assert!(CountdownTimer::from_duration(Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn encodes_12_bit_counter() {
+        let timer = CountdownTimer::new(TimerClock::Hz64, 0x0ABC).unwrap();
+
+        assert_eq!(timer.to_registers(), [0xBC, 0x0A]);
+        assert_eq!(ticks_from_registers([0xBC, 0xFA]), 0x0ABC);
+    }
+}
@@ -1,60 +1,107 @@
 use crate::{error::DriverError, rtc::registers::Register, ClockData};
 use core::fmt::Debug;
 use embedded_hal::i2c::{I2c, SevenBitAddress};
+use embedded_hal_async::i2c::I2c as AsyncI2c;
 
-use super::registers::ClockRegisters;
-
-pub trait Updatable: Debug + Copy + Clone {
-    /// Set the date and time.
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`DriverError`]
-    fn set_datetime<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        addr: u8,
-        cr: &mut ClockRegisters,
-        data: &ClockData,
-    ) -> Result<(), DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>;
+use super::bitfields::ControlReg;
+use super::registers::{ClockRegisters, ClockRegistersAsync};
+
+/// Defines [`Updatable`] and [`UpdatableAsync`] from a single definition, as per
+/// `impl_driver!`.
+macro_rules! updatable {
+    (
+        $(#[$attr:meta])*
+        $updatable:ident, $i2c:ident, $registers:ident, [$($maybe_await:tt)*] $(, $async:tt)?
+    ) => {
+        $(#[$attr])*
+        pub trait $updatable: Debug + Copy + Clone {
+            /// Set the date and time.
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DriverError`]
+            $($async)? fn set_datetime<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                addr: u8,
+                cr: &mut $registers,
+                data: &ClockData,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>;
+        }
+
+        impl $updatable for ClockData {
+            /// Writes Seconds through Year in a single transaction, with the RESET bit asserted
+            /// throughout. Hundredths is cleared by the rtc on reset.
+            $($async)? fn set_datetime<I2C>(
+                &mut self,
+                i2c: &mut I2C,
+                _addr: u8,
+                cu: &mut $registers,
+                data: &ClockData,
+            ) -> Result<(), DriverError<I2C::Error>>
+            where
+                I2C: $i2c<SevenBitAddress>,
+                I2C::Error: Into<DriverError<I2C::Error>>,
+            {
+                // Hold the divider chain in reset, so the clock cannot tick mid-write.
+                cu.modify_bitfield(i2c, |control: &mut ControlReg| control.set_reset(true))
+                    $($maybe_await)*?;
+
+                cu.write_registers(i2c, Register::Seconds, &data.to_registers())$($maybe_await)*?;
+
+                // Release RESET; the sub-second divider chain starts from a known phase.
+                cu.modify_bitfield(i2c, |control: &mut ControlReg| control.set_reset(false))
+                    $($maybe_await)*?;
+
+                Ok(())
+            }
+        }
+    };
 }
 
-impl Updatable for ClockData {
-    fn set_datetime<I2C>(
-        &mut self,
-        i2c: &mut I2C,
-        _addr: u8,
-        cu: &mut ClockRegisters,
-        data: &ClockData,
-    ) -> Result<(), DriverError<I2C::Error>>
-    where
-        I2C: I2c<SevenBitAddress>,
-        I2C::Error: Into<DriverError<I2C::Error>>,
-    {
-        use crate::models::misc::dec_to_bcd;
-
-        // Stored as BCD values.
-        cu.write_register(i2c, Register::Hours, dec_to_bcd(data.hours()))?;
-        cu.write_register(i2c, Register::Minutes, dec_to_bcd(data.minutes()))?;
-        cu.write_register(i2c, Register::Seconds, dec_to_bcd(data.seconds()))?;
-        cu.write_register(i2c, Register::Date, dec_to_bcd(data.date()))?;
-        cu.write_register(i2c, Register::Month, dec_to_bcd(data.month()))?;
-        cu.write_register(i2c, Register::Year, dec_to_bcd(data.year()))?;
-
-        // Single bit value only
-        cu.write_register(i2c, Register::Weekday, data.weekday())?;
-
-        // Set RESET bit to 0 in Control register to prevent seconds getting stuck.
-        cu.write_bit(
-            i2c,
-            Register::Control.address(),
-            Register::ControlReset.address(),
-            false,
-        )?;
-
-        Ok(())
+updatable!(Updatable, I2c, ClockRegisters, []);
+
+updatable!(
+    /// Async counterpart of [`Updatable`].
+    #[allow(async_fn_in_trait)]
+    UpdatableAsync, AsyncI2c, ClockRegistersAsync, [.await], async
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Updatable;
+    use crate::{models::DateTimeBuilder, rtc::registers, ClockData};
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+
+    const ADDR: u8 = 0x32;
+
+    #[test]
+    fn burst_writes_time_while_held_in_reset() {
+        let data = DateTimeBuilder::new()
+            .hours(23)
+            .minutes(59)
+            .seconds(58)
+            .build();
+        let expectations = [
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0000_1000]),
+            Transaction::write(ADDR, vec![0x1F, 0b0000_1001]),
+            Transaction::transaction_start(ADDR),
+            Transaction::write(ADDR, vec![0x11]),
+            Transaction::write(ADDR, vec![0x58, 0x59, 0x23, 0x10, 0x01, 0x01, 0x70]),
+            Transaction::transaction_end(ADDR),
+            Transaction::write_read(ADDR, vec![0x1F], vec![0b0000_1001]),
+            Transaction::write(ADDR, vec![0x1F, 0b0000_1000]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let mut cu = registers::new(ADDR);
+        ClockData::new()
+            .set_datetime(&mut i2c, ADDR, &mut cu, &data)
+            .unwrap();
+
+        i2c.done();
     }
 }
@@ -0,0 +1,16 @@
+//! Tracing of register transactions, through `defmt` and `log` when enabled.
+
+/// Logs at trace level through each enabled backend; expands to nothing otherwise.
+///
+/// The format string is passed to both backends, so is limited to the placeholders they share,
+/// e.g. `{}`, `{:?}` and `{:#x}`.
+macro_rules! trace {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "defmt")]
+        ::defmt::trace!($($arg)*);
+        #[cfg(feature = "log")]
+        ::log::trace!($($arg)*);
+    }};
+}
+
+pub(crate) use trace;